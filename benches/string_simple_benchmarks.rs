@@ -8,6 +8,16 @@ fn compare_benchmark(c: &mut Criterion) {
         black_box(&String::from("this is my test string for benchmarks!")),
         black_box(&String::from("test"))
     )));
+    c.bench_function("contains 16KB rare needle", |b| b.iter(|| {
+        let needles = String::from("test");
+        let chunk = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let mut haystack = String::with_capacity(16384);
+        for _ in 0..255 {
+            haystack.push_str(chunk);
+        }
+        haystack.push_str("test");
+        compare::contains(black_box(&haystack), black_box(&needles))
+    }));
     c.bench_function("contains SIMD", |b| b.iter(|| {
         let needles = String::from("test");
         let chunk = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
@@ -43,6 +53,19 @@ fn compare_benchmark(c: &mut Criterion) {
         compare::count_chars(black_box(&haystack), black_box(&needles))
     }
     ));
+    c.bench_function("char count 20 char group", |b| b.iter(|| {
+        let needles = "abcdefghijklmnopqrst".chars().collect::<Vec<char>>();
+        let chunk = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let mut haystack = String::with_capacity(16384);
+        for _ in 0..255 {
+            haystack.push_str(chunk);
+        }
+        compare::count_chars(black_box(&haystack), black_box(&needles))
+    }));
+    c.bench_function("all substrings from 20 char group", |b| b.iter(|| compare::substring_char_group_count(
+        black_box(&String::from("abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyz")),
+        black_box(&"abcdefghijklmnopqrst".chars().collect::<Vec<char>>())
+    )));
     c.bench_function("char count SIMD", |b| b.iter(|| {
         let needles = vec!['a', 'b', 'c'];
         let chunk = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
@@ -52,6 +75,49 @@ fn compare_benchmark(c: &mut Criterion) {
         }
         compare::count_chars_simd(black_box(&haystack), black_box(&needles))
     }));
+    c.bench_function("word count", |b| b.iter(|| {
+        let chunk = "lorem ipsum dolor\tsit amet,\nconsectetur adipiscing elit sed do ";
+        let mut haystack = String::with_capacity(4 * 1024 * 1024);
+        for _ in 0..65536 {
+            haystack.push_str(chunk);
+        }
+        compare::word_count(black_box(&haystack))
+    }));
+    c.bench_function("word count SIMD", |b| b.iter(|| {
+        let chunk = "lorem ipsum dolor\tsit amet,\nconsectetur adipiscing elit sed do ";
+        let mut haystack = String::with_capacity(4 * 1024 * 1024);
+        for _ in 0..65536 {
+            haystack.push_str(chunk);
+        }
+        compare::word_count_simd(black_box(&haystack))
+    }));
+    c.bench_function("find all 16KB sparse", |b| b.iter(|| {
+        let chunk = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let mut haystack = String::with_capacity(16384 + 6);
+        for _ in 0..256 {
+            haystack.push_str(chunk);
+        }
+        haystack.push_str("needle");
+        compare::find_all_exact(black_box(&haystack), black_box(&"needle"))
+    }));
+    c.bench_function("find all starts SIMD 16KB sparse", |b| b.iter(|| {
+        let chunk = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let mut haystack = String::with_capacity(16384 + 6);
+        for _ in 0..256 {
+            haystack.push_str(chunk);
+        }
+        haystack.push_str("needle");
+        compare::find_all_starts_simd(black_box(&haystack), black_box(&"needle"))
+    }));
+    c.bench_function("contains per line", |b| {
+        let lines: Vec<String> = (0..1000).map(|i| format!("2024-01-01 12:00:{:02} INFO request {} handled", i % 60, i)).collect();
+        b.iter(|| lines.iter().filter(|line| compare::contains(black_box(*line), black_box(&"ERROR"))).count())
+    });
+    c.bench_function("searcher contains per line", |b| {
+        let lines: Vec<String> = (0..1000).map(|i| format!("2024-01-01 12:00:{:02} INFO request {} handled", i % 60, i)).collect();
+        let searcher = compare::Searcher::new(&"ERROR");
+        b.iter(|| lines.iter().filter(|line| searcher.contains(black_box(*line))).count())
+    });
     c.bench_function("find all", |b| b.iter(|| compare::find_all_exact(
         black_box(&String::from("aaabbbbccc")),
         black_box(&String::from("abbb"))
@@ -0,0 +1,32 @@
+//! Build test for `string-simple` without its default `std` feature.
+//! Build it for a target without `std` to check the crate only needs `core` and `alloc`:
+//!
+//! ```text
+//! cargo build --target thumbv7em-none-eabihf
+//! ```
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use string_simple::builder::StringBuilder;
+use string_simple::compare;
+use string_simple::modify;
+
+pub fn build_list(items: &[&str]) -> String {
+    let mut builder = StringBuilder::separated(", ");
+    for item in items {
+        builder.append(item);
+    }
+    builder.build()
+}
+
+pub fn normalize(base: &mut String) {
+    modify::replace(base, &"\t", &"    ");
+    modify::make_title_case(base);
+}
+
+pub fn search(base: &str, find: &str) -> (bool, Vec<(usize, usize)>) {
+    (compare::contains(&base, &find), compare::find_all_exact(&base, &find))
+}
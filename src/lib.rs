@@ -1,9 +1,14 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![feature(portable_simd)]
 
+extern crate alloc;
 extern crate core;
 
 pub mod builder {
-    use std::ops::AddAssign;
+    use alloc::borrow::Cow;
+    use alloc::string::{String, ToString};
+    use core::fmt::Write;
+    use core::ops::{Add, AddAssign};
 
     /// # Description
     /// A simple struct to wrap the process of building strings.
@@ -46,16 +51,69 @@ pub mod builder {
     /// // result = "even odd even odd..."
     /// let result = new_builder.build();
     /// ```
+    ///
+    /// Operator example:
+    /// ```
+    /// use string_simple::builder::StringBuilder;
+    ///
+    /// let mut new_builder = StringBuilder::new() + "a";
+    /// new_builder += "b";
+    /// new_builder += String::from("c");
+    ///
+    /// // result = "abc"
+    /// let result = new_builder.build();
+    /// ```
+    ///
+    /// Separated example:
+    /// ```
+    /// use string_simple::builder::StringBuilder;
+    ///
+    /// let mut new_builder = StringBuilder::separated(", ");
+    ///
+    /// new_builder.append("a")
+    ///     .append("b")
+    ///     .append("c");
+    ///
+    /// // result = "a, b, c"
+    /// let result = new_builder.build();
+    /// ```
     pub struct StringBuilder {
         full_string: String,
-        current_len: usize
+        current_len: usize,
+        separator: Option<String>,
+        has_appended: bool,
+        has_query: bool
+    }
+
+    /// # Description
+    /// A saved state of a `StringBuilder`, returned by `checkpoint` and restored by `rollback_to`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Checkpoint {
+        len: usize,
+        has_appended: bool,
+        has_query: bool
     }
 
     impl StringBuilder {
         pub fn new() -> Self {
             StringBuilder {
                 full_string: String::with_capacity(0),
-                current_len: 0
+                current_len: 0,
+                separator: None,
+                has_appended: false,
+                has_query: false
+            }
+        }
+
+        /// # Description
+        /// Creates a builder that inserts `sep` before every appended value except the first, so the result never has a leading or trailing separator.
+        pub fn separated(sep: &str) -> Self {
+            StringBuilder {
+                full_string: String::with_capacity(0),
+                current_len: 0,
+                separator: Some(sep.to_string()),
+                has_appended: false,
+                has_query: false
             }
         }
 
@@ -66,11 +124,18 @@ pub mod builder {
             where T: ToString
         {
             let str = t.to_string();
+            let sep = match (&self.separator, self.has_appended) {
+                (Some(sep), true) => sep.as_str(),
+                _ => ""
+            };
             let mut len = self.current_len;
+            len.add_assign(sep.len());
             len.add_assign(AsRef::<str>::as_ref(&str).len());
             let mut buf = String::with_capacity(len);
             buf.push_str(self.full_string.as_ref());
+            buf.push_str(sep);
             buf.push_str(str.as_ref());
+            self.has_appended = true;
             self.current_len = len;
             self.full_string = buf;
             self
@@ -79,12 +144,363 @@ pub mod builder {
         pub fn build(&self) -> String {
             self.full_string.clone()
         }
+
+        /// # Description
+        /// Borrows the bytes of the string built so far, without cloning it like `build` does.
+        pub fn as_bytes(&self) -> &[u8] {
+            self.full_string.as_bytes()
+        }
+
+        /// # Description
+        /// Maps every item through `f` and appends the results in order, the same as calling `append` for each mapped item.
+        pub fn append_map<I, T, F, O>(
+            &mut self,
+            items: I,
+            mut f: F
+        ) -> &mut Self
+            where I: IntoIterator<Item = T>, F: FnMut(T) -> O, O: ToString
+        {
+            for item in items {
+                self.push_value(f(item).to_string().as_str());
+            }
+            self
+        }
+
+        /// # Description
+        /// Appends `t` padded with `fill` to `width` chars. When `left` is true the value is left aligned and the padding goes after it,
+        /// otherwise it is right aligned. Values already `width` chars or wider are appended unchanged.
+        pub fn append_padded<T>(
+            &mut self,
+            t: T,
+            width: usize,
+            fill: char,
+            left: bool
+        ) -> &mut Self
+            where T: ToString
+        {
+            let str = t.to_string();
+            let padding: String = core::iter::repeat_n(fill, width.saturating_sub(str.chars().count())).collect();
+            let mut padded = String::with_capacity(str.len() + padding.len());
+            match left {
+                true => {
+                    padded.push_str(str.as_ref());
+                    padded.push_str(padding.as_ref());
+                }
+                false => {
+                    padded.push_str(padding.as_ref());
+                    padded.push_str(str.as_ref());
+                }
+            }
+            self.push_value(padded.as_ref());
+            self
+        }
+
+        /// # Description
+        /// Decodes UTF-16 code units and appends the result. This is lossy: unpaired surrogates are replaced with `'\u{FFFD}'` instead of failing.
+        pub fn append_utf16(&mut self, units: &[u16]) -> &mut Self {
+            let decoded: String = char::decode_utf16(units.iter().copied())
+                .map(|unit| unit.unwrap_or(char::REPLACEMENT_CHARACTER))
+                .collect();
+            self.push_value(decoded.as_ref());
+            self
+        }
+
+        /// # Description
+        /// Inserts `t` at a char (not byte) position in the string built so far.
+        ///
+        /// # Panics
+        /// Panics if `char_index` is greater than the number of chars in the builder.
+        pub fn insert<T>(
+            &mut self,
+            char_index: usize,
+            t: T
+        ) -> &mut Self
+            where T: ToString
+        {
+            let byte_index = match self.full_string.char_indices().nth(char_index) {
+                Some((byte_index, _)) => byte_index,
+                None => {
+                    let char_count = self.full_string.chars().count();
+                    assert!(char_index == char_count, "char index {} is out of bounds for a builder of {} chars", char_index, char_count);
+                    self.full_string.len()
+                }
+            };
+            self.full_string.insert_str(byte_index, t.to_string().as_ref());
+            self.current_len = self.full_string.len();
+            self
+        }
+
+        /// # Description
+        /// Appends `s` escaped for use inside a JSON string: quotes, backslashes and control chars are escaped. The surrounding quotes are not added.
+        pub fn append_json_escaped(&mut self, s: &str) -> &mut Self {
+            self.start_value();
+            push_json_escaped(&mut self.full_string, s);
+            self.current_len = self.full_string.len();
+            self
+        }
+
+        /// # Description
+        /// Appends a JSON object member `"key":"value"` with the key and value escaped, preceded by a comma unless `first` is true.
+        /// The braces of the object are left to the caller, and the separator of a separated builder is not inserted.
+        pub fn append_json_field(&mut self, key: &str, value: &str, first: bool) -> &mut Self {
+            if !first {
+                self.full_string.push(',');
+            }
+            self.full_string.push('"');
+            push_json_escaped(&mut self.full_string, key);
+            self.full_string.push_str("\":\"");
+            push_json_escaped(&mut self.full_string, value);
+            self.full_string.push('"');
+            self.current_len = self.full_string.len();
+            self
+        }
+
+        /// # Description
+        /// Appends `s` with `<`, `>`, `&` and quotes replaced by HTML entities.
+        pub fn append_html_escaped(&mut self, s: &str) -> &mut Self {
+            self.start_value();
+            for c in s.chars() {
+                match c {
+                    '&' => self.full_string.push_str("&amp;"),
+                    '<' => self.full_string.push_str("&lt;"),
+                    '>' => self.full_string.push_str("&gt;"),
+                    '"' => self.full_string.push_str("&quot;"),
+                    '\'' => self.full_string.push_str("&#39;"),
+                    _ => self.full_string.push(c)
+                }
+            }
+            self.current_len = self.full_string.len();
+            self
+        }
+
+        /// # Description
+        /// Appends a single char straight into the buffer. Unlike `append('c')` this does not allocate a `String` for the char.
+        pub fn append_char(&mut self, c: char) -> &mut Self {
+            self.start_value();
+            self.full_string.push(c);
+            self.current_len = self.full_string.len();
+            self
+        }
+
+        /// # Description
+        /// Appends every item in order straight into the buffer, the same as calling `append` for each item but without copying the buffer each time.
+        /// Nothing is put between the items unless the builder was created with `separated`.
+        pub fn append_all<I, T>(&mut self, items: I) -> &mut Self
+            where I: IntoIterator<Item = T>, T: ToString
+        {
+            for item in items {
+                self.push_value(item.to_string().as_str());
+            }
+            self
+        }
+
+        /// # Description
+        /// Replaces every occurrence of `find` in the string built so far with `replacement`, useful for a post-processing pass without going through `build`.
+        /// The matching is done by `modify::replace_cow`, so the buffer is only rebuilt when `find` occurs. An empty `find` replaces nothing.
+        pub fn replace(&mut self, find: &str, replacement: &str) -> &mut Self {
+            if let Cow::Owned(replaced) = crate::modify::replace_cow(&self.full_string, &find, &replacement) {
+                self.full_string = replaced;
+                self.current_len = self.full_string.len();
+            }
+            self
+        }
+
+        /// # Description
+        /// Appends an integer with `sep` between every group of three digits, e.g. `1234567` with `','` becomes `"1,234,567"`.
+        /// Negative numbers keep their sign in front and numbers below 1000 are appended without a separator.
+        pub fn append_grouped(&mut self, n: i64, sep: char) -> &mut Self {
+            let digits = n.unsigned_abs().to_string();
+            self.start_value();
+            if n < 0 {
+                self.full_string.push('-');
+            }
+            for (index, digit) in digits.chars().enumerate() {
+                if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                    self.full_string.push(sep);
+                }
+                self.full_string.push(digit);
+            }
+            self.current_len = self.full_string.len();
+            self
+        }
+
+        /// # Description
+        /// Appends a float rounded to `precision` decimal places, without formatting it into a separate `String` first.
+        /// NaN is written as `"NaN"` and infinities as `"inf"` or `"-inf"`, regardless of the precision.
+        pub fn append_float(&mut self, value: f64, precision: usize) -> &mut Self {
+            self.start_value();
+            let _ = write!(self.full_string, "{:.*}", precision, value);
+            self.current_len = self.full_string.len();
+            self
+        }
+
+        /// # Description
+        /// Appends every item followed by `\n`, including the last one, so the result can be written out as a file or log as is.
+        /// For a builder created with `separated` the lines count as a single value, the separator is not put between them.
+        pub fn append_lines<I, T>(&mut self, lines: I) -> &mut Self
+            where I: IntoIterator<Item = T>, T: ToString
+        {
+            self.start_value();
+            for line in lines {
+                self.full_string.push_str(line.to_string().as_str());
+                self.full_string.push('\n');
+            }
+            self.current_len = self.full_string.len();
+            self
+        }
+
+        /// # Description
+        /// Appends an integer right aligned in a field of `width` spaces, for lining up numbers in table columns.
+        /// A number wider than `width` is appended unpadded. The same as `append_padded(n, width, ' ', false)`.
+        pub fn append_int_right_aligned(&mut self, n: i64, width: usize) -> &mut Self {
+            self.append_padded(n, width, ' ', false)
+        }
+
+        /// # Description
+        /// Appends the hex encoding of `bytes`, two hex digits per byte written straight into the buffer, e.g. for hashes and hex dumps.
+        pub fn append_hex(&mut self, bytes: &[u8], uppercase: bool) -> &mut Self {
+            let digits: &[u8; 16] = match uppercase {
+                true => b"0123456789ABCDEF",
+                false => b"0123456789abcdef"
+            };
+            self.start_value();
+            self.full_string.reserve(bytes.len() * 2);
+            for byte in bytes {
+                self.full_string.push(digits[(byte >> 4) as usize] as char);
+                self.full_string.push(digits[(byte & 0x0F) as usize] as char);
+            }
+            self.current_len = self.full_string.len();
+            self
+        }
+
+        /// # Description
+        /// Appends `sep` as-is only when the buffer already holds something, so a delimited list can be built with `append_separator(", ").append(item)` and never starts with a separator.
+        pub fn append_separator(&mut self, sep: &str) -> &mut Self {
+            if !self.full_string.is_empty() {
+                self.full_string.push_str(sep);
+                self.current_len = self.full_string.len();
+            }
+            self
+        }
+
+        /// # Description
+        /// Appends a URL query parameter, `?key=value` for the first one and `&key=value` after that, with the key and value percent-encoded.
+        /// The separator of a separated builder is not inserted before a parameter.
+        pub fn append_query_param(&mut self, key: &str, value: &str) -> &mut Self {
+            match self.has_query {
+                true => self.full_string.push('&'),
+                false => self.full_string.push('?')
+            }
+            self.has_query = true;
+            push_percent_encoded(&mut self.full_string, key);
+            self.full_string.push('=');
+            push_percent_encoded(&mut self.full_string, value);
+            self.current_len = self.full_string.len();
+            self
+        }
+
+        /// # Description
+        /// Saves the current state of the builder, to be passed to `rollback_to` later to undo whatever is appended after this point.
+        pub fn checkpoint(&self) -> Checkpoint {
+            Checkpoint {
+                len: self.full_string.len(),
+                has_appended: self.has_appended,
+                has_query: self.has_query
+            }
+        }
+
+        /// # Description
+        /// Restores a state saved with `checkpoint`, e.g. to undo speculatively appended content on a parse error.
+        /// The buffer is truncated back to its saved length and the separator and query param state are restored with it.
+        /// Panics if the checkpoint is past the current length or not on a char boundary, e.g. when it was taken from another builder.
+        pub fn rollback_to(&mut self, checkpoint: Checkpoint) -> &mut Self {
+            let len = checkpoint.len;
+            assert!(len <= self.full_string.len(), "checkpoint {} is past the current length {}", len, self.full_string.len());
+            assert!(self.full_string.is_char_boundary(len), "checkpoint {} is not on a char boundary", len);
+            self.full_string.truncate(len);
+            self.current_len = len;
+            self.has_appended = checkpoint.has_appended;
+            self.has_query = checkpoint.has_query;
+            self
+        }
+
+        fn start_value(&mut self) {
+            if let (Some(sep), true) = (&self.separator, self.has_appended) {
+                self.full_string.push_str(sep);
+            }
+            self.has_appended = true;
+        }
+
+        fn push_value(&mut self, value: &str) {
+            self.start_value();
+            self.full_string.push_str(value);
+            self.current_len = self.full_string.len();
+        }
+    }
+
+    impl AddAssign<&str> for StringBuilder {
+        fn add_assign(&mut self, rhs: &str) {
+            self.push_value(rhs);
+        }
+    }
+
+    impl AddAssign<String> for StringBuilder {
+        fn add_assign(&mut self, rhs: String) {
+            self.push_value(rhs.as_ref());
+        }
+    }
+
+    impl Add<&str> for StringBuilder {
+        type Output = StringBuilder;
+
+        fn add(mut self, rhs: &str) -> StringBuilder {
+            self.push_value(rhs);
+            self
+        }
+    }
+
+    fn push_percent_encoded(buf: &mut String, s: &str) {
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+        for byte in s.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => buf.push(byte as char),
+                _ => {
+                    buf.push('%');
+                    buf.push(HEX[(byte >> 4) as usize] as char);
+                    buf.push(HEX[(byte & 0x0F) as usize] as char);
+                }
+            }
+        }
+    }
+
+    fn push_json_escaped(buf: &mut String, s: &str) {
+        for c in s.chars() {
+            match c {
+                '"' => buf.push_str("\\\""),
+                '\\' => buf.push_str("\\\\"),
+                '\n' => buf.push_str("\\n"),
+                '\r' => buf.push_str("\\r"),
+                '\t' => buf.push_str("\\t"),
+                '\u{08}' => buf.push_str("\\b"),
+                '\u{0C}' => buf.push_str("\\f"),
+                c if (c as u32) < 0x20 => {
+                    const HEX: &[u8; 16] = b"0123456789abcdef";
+                    buf.push_str("\\u00");
+                    buf.push(HEX[(c as usize) >> 4] as char);
+                    buf.push(HEX[(c as usize) & 0xF] as char);
+                }
+                _ => buf.push(c)
+            }
+        }
     }
 }
 
 
 pub mod modify {
-    use std::ops::AddAssign;
+    use alloc::borrow::Cow;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+    use core::ops::AddAssign;
 
     /// # Description
     ///
@@ -202,515 +618,5743 @@ pub mod modify {
         }
         *base = replaced_string
     }
-}
-
-
-pub mod compare {
-    use std::collections::HashMap;
-    use std::ops::{Add, Sub};
-    use std::simd::{Simd, u8x64};
-    use std::simd::cmp::SimdPartialEq;
-    use std::simd::num::{SimdInt, SimdUint};
-
 
     /// # Description
     ///
-    /// Finds all substrings containing a set of specified characters.
-    /// Returns a HashMap containing all substrings as keys and the value being the number of times the substring occurs in the base string.
+    /// Removes a prefix from the base string if the base string starts with it. The base string is edited in place, no new buffer is allocated.
     ///
     /// # Arguments
     ///
-    /// * `base` - The base string being searched.
-    /// * `char_group` - the specified chars being looked for in substrings.
+    /// * `base` - The base string that will be modified.
+    /// * `prefix` - The prefix being removed.
     ///
-    /// # Outputs
+    /// # Output
     ///
-    /// * HashMap<String, usize> - HashMap of all strings as keys and the number of times the substring occurs as the value.
+    /// * `bool` - Whether the prefix was present and removed.
     ///
     /// # Examples
     ///
     /// ```
-    /// use string_simple::compare::substring_char_group_count;
+    /// use string_simple::modify::strip_prefix;
     ///
-    /// let str1 = String::from("abcc");
-    /// let chars = vec!['a', 'b', 'c'];
+    /// let mut base_string = String::from("www.example.com");
     ///
-    /// // The result will look like this: {'abc': 1, 'abcc': 1}
-    /// let result = substring_char_group_count(&str1, &chars);
+    /// // The base string will be "example.com" and result will be true
+    /// let result = strip_prefix(&mut base_string, &"www.");
     /// ```
-    pub fn substring_char_group_count<B>(
-        base: &B,
-        char_group: &Vec<char>
-    ) -> HashMap<String, usize>
-        where B: ToString
+    pub fn strip_prefix<S>(base: &mut String, prefix: &S) -> bool
+        where S: ToString
     {
-        let binding = base.to_string();
-        let haystack = binding.as_bytes();
-        let mut base_pos = 0usize;
-        let mut sub_string_count: HashMap<String, usize> = HashMap::new();
-        let mut sub_byte_count: HashMap<&[u8], usize> = HashMap::new();
-
-        while base_pos < haystack.len() {
-            let mut end_pos = haystack.len();
-            let current = base_pos;
-
-            while current < end_pos {
-                let mut found_count_down = char_group.len();
-
-                for char in char_group {
-                    let byte = *char as u8;
-                    if haystack[current..end_pos].contains(&byte) {
-                        found_count_down -= 1
-                    }
-                }
-
-                match (found_count_down == 0, sub_byte_count.get(&haystack[current..end_pos])) {
-                    (true, Some(count)) => sub_byte_count.insert(&haystack[current..end_pos], count + 1usize),
-                    (true, None) => sub_byte_count.insert(&haystack[current..end_pos], 1usize),
-                    _ => None
-                };
-                end_pos -= 1;
-            }
-            base_pos += 1;
-        }
-
-        for (byte_arr, count) in sub_byte_count {
-            sub_string_count.insert(unsafe { std::str::from_utf8_unchecked(byte_arr).to_string() }, count);
+        let prefix = prefix.to_string();
+        if !base.starts_with(prefix.as_str()) {
+            return false;
         }
-        sub_string_count
+        base.drain(..prefix.len());
+        true
     }
 
     /// # Description
     ///
-    /// Gets the count of all characters fom a provided base string given a group of characters.
+    /// Removes a suffix from the base string if the base string ends with it. The base string is edited in place, no new buffer is allocated.
     ///
     /// # Arguments
     ///
-    /// * `base` - The base string being searched.
-    /// * `chars` - A vector of characters being searched and tallied.
+    /// * `base` - The base string that will be modified.
+    /// * `suffix` - The suffix being removed.
     ///
     /// # Output
     ///
-    /// * HashMap<char, u32> - A hashmap containing the different characters and the number of times they appear in the base string.
+    /// * `bool` - Whether the suffix was present and removed.
     ///
     /// # Examples
     ///
     /// ```
-    /// use string_simple::compare::count_chars;
+    /// use string_simple::modify::strip_suffix;
     ///
-    /// let str1 = String::from("abc");
-    /// let chars = vec!['a', 'b', 'c'];
+    /// let mut base_string = String::from("archive.tar.gz");
     ///
-    /// // The result will look like this: {'a': 1, 'b': 1, 'c': 1}
-    /// let result = count_chars(&str1, &chars);
+    /// // The base string will be "archive.tar" and result will be true
+    /// let result = strip_suffix(&mut base_string, &".gz");
     /// ```
-    pub fn count_chars<B>(
-        base: &B,
-        chars: &Vec<char>
-    ) -> HashMap<char, usize>
-        where B: ToString
+    pub fn strip_suffix<S>(base: &mut String, suffix: &S) -> bool
+        where S: ToString
     {
-        let binding = base.to_string();
-        let bytes = binding.as_bytes();
-        let mut char_count: HashMap<char, usize> = HashMap::new();
-        for c_char in chars {
-            let mut pos = 0usize;
-            let mut count = 0usize;
-            while pos < bytes.len() {
-                if c_char == &(bytes[pos] as char) {
-                    count += 1;
-                }
-                pos += 1;
-            }
-            char_count.insert(*c_char, count);
+        let suffix = suffix.to_string();
+        if !base.ends_with(suffix.as_str()) {
+            return false;
         }
-
-        char_count
+        base.truncate(base.len() - suffix.len());
+        true
     }
 
-    pub fn count_chars_simd<B>(
-        base: &B,
-        chars: &Vec<char>
-    ) -> HashMap<char, usize>
-        where B: ToString
-    {
-        let binding = base.to_string();
-        let haystack = binding.as_bytes();
-        let mut needles: HashMap<char, usize> = HashMap::new();
-        for c_char in chars {
-            let needle = *c_char as u8;
-            let count = needle_count_simd(haystack, needle);
-            needles.insert(*c_char, count);
+    /// # Description
+    ///
+    /// Replaces the base string with `times` concatenated copies of itself. The final capacity is reserved up front.
+    /// Repeating 0 times empties the base string and repeating once leaves it unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `times` - The number of copies the base string will contain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::repeat;
+    ///
+    /// let mut base_string = String::from("ab");
+    ///
+    /// // The base string will be "ababab"
+    /// repeat(&mut base_string, 3);
+    /// ```
+    pub fn repeat(base: &mut String, times: usize) {
+        match times {
+            0 => base.clear(),
+            1 => {}
+            _ => {
+                let original = base.clone();
+                base.reserve(original.len() * (times - 1));
+                for _ in 1..times {
+                    base.push_str(original.as_ref());
+                }
+            }
         }
-        needles
     }
 
-    fn simd_u8x64_from_offset(slice: &[u8], offset: usize) -> u8x64 {
-        let slice = unsafe { slice.get_unchecked(offset..) };
-        if slice.len() < 64 {
-            let mut padded_slice: [u8; 64] = [0; 64];
-            copy_to_arr_with_padding(&mut padded_slice, slice);
-            u8x64::from_slice(padded_slice.as_slice())
-        } else {
-            u8x64::from_slice(slice)
+    /// # Description
+    ///
+    /// Replaces all occurrences of a substring without modifying the base string.
+    /// When `find` never occurs the base string is borrowed back and nothing is allocated or copied, a new string is only built when a replacement happens.
+    /// Occurrences are replaced left to right and do not overlap. An empty `find` never matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The full base string.
+    /// * `find` - The substring we are going to replace in the `base` string.
+    /// * `replace` - The new string that replaces all occurrences of the `find` string.
+    ///
+    /// # Output
+    ///
+    /// * `Cow<str>` - `Cow::Borrowed(base)` if nothing was replaced, otherwise `Cow::Owned` containing the replaced string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::replace_cow;
+    ///
+    /// // result will be Cow::Owned("This is my modified string!")
+    /// let result = replace_cow("This is my base string!", &"base", &"modified");
+    /// ```
+    pub fn replace_cow<'a, S, R>(base: &'a str, find: &S, replace: &R) -> Cow<'a, str>
+        where S: ToString, R: ToString
+    {
+        let find = find.to_string();
+        let first_match = match find.is_empty() {
+            true => None,
+            false => base.find(find.as_str())
+        };
+        let first_match = match first_match {
+            Some(first_match) => first_match,
+            None => return Cow::Borrowed(base)
+        };
+
+        let replace = replace.to_string();
+        let mut replaced_string = String::with_capacity(base.len());
+        replaced_string.push_str(&base[..first_match]);
+        let mut current_base_pos = first_match;
+        while let Some(found) = base[current_base_pos..].find(find.as_str()) {
+            replaced_string.push_str(&base[current_base_pos..current_base_pos + found]);
+            replaced_string.push_str(replace.as_ref());
+            current_base_pos += found + find.len();
         }
+        replaced_string.push_str(&base[current_base_pos..]);
+        Cow::Owned(replaced_string)
     }
 
-    fn simd_sum_x64(u8s: &u8x64) -> usize {
-        let mut store = [0; size_of::<u8x64>()];
-        u8s.copy_to_slice(&mut store);
-        store.iter().map(|&e| e as usize).sum()
-    }
+    /// # Description
+    ///
+    /// Converts the base string to title case: the first char of every whitespace delimited word is uppercased and the rest of the word is lowercased.
+    /// Apostrophes and hyphens do not start a new word, so `"don't"` becomes `"Don't"` and `"jean-luc"` becomes `"Jean-luc"`.
+    /// ASCII strings are edited in place, other strings are rebuilt because changing case can change a char's encoded length.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::make_title_case;
+    ///
+    /// let mut base_string = String::from("hELLO wORLD");
+    ///
+    /// // The base string will be "Hello World"
+    /// make_title_case(&mut base_string);
+    /// ```
+    pub fn make_title_case(base: &mut String) {
+        let mut word_start = true;
+        if base.is_ascii() {
+            for pos in 0..base.len() {
+                let is_whitespace = (base.as_bytes()[pos] as char).is_whitespace();
+                let current = &mut base[pos..pos + 1];
+                match (is_whitespace, word_start) {
+                    (true, _) => {}
+                    (false, true) => current.make_ascii_uppercase(),
+                    (false, false) => current.make_ascii_lowercase()
+                }
+                word_start = is_whitespace;
+            }
+            return;
+        }
 
-    fn copy_to_arr_with_padding(dest: &mut [u8], src: &[u8]) {
-        if dest.len() == src.len() {
-            dest.copy_from_slice(src);
-        } else if dest.len() > src.len() {
-            dest[..src.len()].copy_from_slice(src);
-        } else {
-            dest.copy_from_slice(&src[..dest.len()]);
+        let mut title_string = String::with_capacity(base.len());
+        for c in base.chars() {
+            match (c.is_whitespace(), word_start) {
+                (true, _) => title_string.push(c),
+                (false, true) => title_string.extend(c.to_uppercase()),
+                (false, false) => title_string.extend(c.to_lowercase())
+            }
+            word_start = c.is_whitespace();
         }
+        *base = title_string;
     }
 
-    fn needle_count_simd(haystack: &[u8], needle: u8) -> usize {
-        let needle = u8x64::splat(needle);
-        let loops = haystack.len() / 64 + 1;
-        let mut counts = u8x64::splat(0);
-        let mut offset = 0;
-        for _ in 0..loops {
-            let res = simd_u8x64_from_offset(haystack, offset).simd_eq(needle).to_int().cast();
-            counts -= u8x64::from(res);
-            offset += 64;
+    /// # Description
+    ///
+    /// Pads both sides of the base string with `fill` so it is centered within `width` chars.
+    /// When the padding can not be split evenly the extra fill char goes on the right side.
+    /// A base string that is already `width` chars or wider is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `width` - The width in chars the base string is centered in.
+    /// * `fill` - The char used for padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::center;
+    ///
+    /// let mut base_string = String::from("hi");
+    ///
+    /// // The base string will be "**hi**"
+    /// center(&mut base_string, 6, '*');
+    /// ```
+    pub fn center(base: &mut String, width: usize, fill: char) {
+        let padding = width.saturating_sub(base.chars().count());
+        if padding == 0 {
+            return;
         }
-        simd_sum_x64(&counts)
+        let left = padding / 2;
+        let mut buf = String::with_capacity(base.len() + padding * fill.len_utf8());
+        buf.extend(core::iter::repeat_n(fill, left));
+        buf.push_str(base.as_ref());
+        buf.extend(core::iter::repeat_n(fill, padding - left));
+        *base = buf;
     }
 
     /// # Description
     ///
-    /// Finds all sub-string occurrences and ranges the sub-strings occur at.
-    /// All provided arguments are assumed to be valid UTF-8 chars.
+    /// Replaces selected occurrences of a substring in the base string, computing each replacement separately.
+    /// Occurrences are found left to right without overlapping and are numbered from 0. Each number is passed to `should_replace`
+    /// to decide whether that occurrence is replaced, and to `replacement` to produce its new text. An empty `find` never matches.
     ///
     /// # Arguments
-    /// * `base` - The base string we are searching.
-    /// * `find` - The sub-string we are trying to find all occurrences of.
     ///
-    /// # Output
-    /// * `Vec<(usize, usize)>` - A vector of tuples containing 2 usize numbers. The first number is the starting position of the occurrence, the second number is where the occurrence ends. The vector will be empty if no occurrences were found.
+    /// * `base` - The base string that will be modified.
+    /// * `find` - The substring being replaced.
+    /// * `should_replace` - Decides from the occurrence number whether the occurrence is replaced.
+    /// * `replacement` - Produces the replacement text from the occurrence number.
     ///
     /// # Examples
     ///
     /// ```
-    /// use string_simple::compare::find_all_exact;
+    /// use string_simple::modify::replace_where;
     ///
-    /// let base_string = String::from("This is my test string! test test!");
-    /// let find_string = String::from("test");
+    /// let mut base_string = String::from("x x x x");
     ///
-    /// // output in this case will look like this: [(11, 15), (24, 28), (29, 33)]
-    /// let result = find_all_exact(&base_string, &find_string);
+    /// // The base string will be "1 x 3 x"
+    /// replace_where(&mut base_string, &"x", |index| index % 2 == 0, |index| (index + 1).to_string());
     /// ```
-    pub fn find_all_exact<B, S>(
-        base: &B,
-        find: &S
-    ) -> Vec<(usize, usize)>
-        where B: ToString, S: ToString
+    pub fn replace_where<S, F, R>(base: &mut String, find: &S, should_replace: F, replacement: R)
+        where S: ToString, F: Fn(usize) -> bool, R: Fn(usize) -> String
     {
-        let t = base.to_string();
-        let base_string_bytes = t.as_bytes();
-        let t = find.to_string();
-        let find_string_bytes = t.as_bytes();
-        assert!(base_string_bytes.len() >= find_string_bytes.len());
-        let mut matches: Vec<(usize, usize)> = vec![];
-
+        let find = find.to_string();
+        if find.is_empty() {
+            return;
+        }
+        let mut replaced_string = String::with_capacity(base.len());
         let mut current_base_pos = 0usize;
-        while current_base_pos < base_string_bytes.len() {
-            let mut current_find_pos = 0usize;
-            let mut current_base_test = current_base_pos;
-            'inner: while current_find_pos < find_string_bytes.len()
-                && current_base_test < base_string_bytes.len() {
-                match (&base_string_bytes[current_base_test] == &find_string_bytes[current_find_pos],
-                       current_find_pos == find_string_bytes.len() - 1) {
-                    (true, true) => {
-                        matches.push((current_base_pos, current_base_test + 1));
-                        break 'inner;
-                    }
-                    (true, false) => {
-                        current_find_pos += 1;
-                        current_base_test += 1;
-                        continue 'inner;
-                    }
-                    (_, _) => break 'inner
-                }
+        let mut match_index = 0usize;
+        while let Some(found) = base[current_base_pos..].find(find.as_str()) {
+            let match_start = current_base_pos + found;
+            replaced_string.push_str(&base[current_base_pos..match_start]);
+            match should_replace(match_index) {
+                true => replaced_string.push_str(replacement(match_index).as_ref()),
+                false => replaced_string.push_str(find.as_ref())
             }
-            current_base_pos += 1;
+            current_base_pos = match_start + find.len();
+            match_index += 1;
         }
-        matches
+        replaced_string.push_str(&base[current_base_pos..]);
+        *base = replaced_string;
     }
 
     /// # Description
-    /// Find the first occurrence of a sub-string within a base string.
-    /// All arguments are assumed to be valid UTF-8 characters.
+    ///
+    /// Removes every occurrence of any char in a set from the base string. The base string is edited in place, char by char, so it stays valid UTF-8.
     ///
     /// # Arguments
-    /// * `base` - The provided base string we are searching.
-    /// * `find` - The substring we are trying to find.
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `chars` - The chars being removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::remove_chars;
+    ///
+    /// let mut base_string = String::from("banana apple");
+    ///
+    /// // The base string will be "bnn ppl"
+    /// remove_chars(&mut base_string, &['a', 'e']);
+    /// ```
+    pub fn remove_chars(base: &mut String, chars: &[char]) {
+        base.retain(|c| !chars.contains(&c));
+    }
+
+    /// # Description
+    ///
+    /// Trims leading and trailing whitespace without allocating.
+    /// A borrowed string is trimmed by returning a sub-slice of it, and an owned string is trimmed in place inside its existing buffer.
+    /// An already trimmed string is handed back untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The string being trimmed, either borrowed (`&str`) or owned (`String`).
     ///
     /// # Output
-    /// * `Option<(usize, usize)>` - Optional tuple containing the start and end positions in the `base`  where the first `find` can be found. Returns `None` if the `find` was not found.
+    ///
+    /// * `Cow<str>` - The trimmed string, borrowed if `base` was borrowed and owned if `base` was owned.
     ///
     /// # Examples
     ///
     /// ```
-    /// use string_simple::compare::contains;
-    /// let base_string = String::from("This is my test string! test test!");
-    /// let find_string = String::from("test");
+    /// use string_simple::modify::trim_cow;
     ///
-    /// // output in this case will look like this: Some((11, 15))
-    /// let result = contains(&base_string, &find_string);
+    /// // result will be Cow::Owned("padded"), reusing the original buffer
+    /// let result = trim_cow(String::from("  padded \n"));
     /// ```
-    pub fn contains<B, S>(
-        haystack: &B,
-        needle: &S
-    ) -> bool
-        where B: ToString, S: ToString
+    pub fn trim_cow<'a, S>(base: S) -> Cow<'a, str>
+        where S: Into<Cow<'a, str>>
     {
-        let t = haystack.to_string();
-        let haystack = t.as_bytes();
-        let t = needle.to_string();
-        let needle = t.as_bytes();
-        assert!(haystack.len() >= needle.len());
+        match base.into() {
+            Cow::Borrowed(borrowed) => Cow::Borrowed(borrowed.trim()),
+            Cow::Owned(mut owned) => {
+                let end = owned.trim_end().len();
+                owned.truncate(end);
+                let start = owned.len() - owned.trim_start().len();
+                owned.drain(..start);
+                Cow::Owned(owned)
+            }
+        }
+    }
+
+    /// # Description
+    ///
+    /// Replaces every tab with enough spaces to reach the next column that is a multiple of `tab_width`.
+    /// Columns are counted in chars from the start of each line, so alignment is kept after `\n`. A `tab_width` of 0 removes tabs.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `tab_width` - The distance in columns between tab stops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::expand_tabs;
+    ///
+    /// let mut base_string = String::from("a\tb");
+    ///
+    /// // The base string will be "a   b"
+    /// expand_tabs(&mut base_string, 4);
+    /// ```
+    pub fn expand_tabs(base: &mut String, tab_width: usize) {
+        if !base.contains('\t') {
+            return;
+        }
+        let mut expanded = String::with_capacity(base.len());
+        let mut column = 0usize;
+        for c in base.chars() {
+            match c {
+                '\t' if tab_width > 0 => {
+                    let spaces = tab_width - column % tab_width;
+                    expanded.extend(core::iter::repeat_n(' ', spaces));
+                    column += spaces;
+                }
+                '\t' => {}
+                '\n' => {
+                    expanded.push(c);
+                    column = 0;
+                }
+                _ => {
+                    expanded.push(c);
+                    column += 1;
+                }
+            }
+        }
+        *base = expanded;
+    }
 
+    /// # Description
+    ///
+    /// Replaces all occurrences of a substring with text computed by a closure. The closure is called once per occurrence, in order,
+    /// with the matched text, so it can keep state such as a counter. Occurrences are found left to right without overlapping. An empty `find` never matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `find` - The substring being replaced.
+    /// * `replacer` - Produces the replacement for each occurrence from the matched text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::replace_with;
+    ///
+    /// let mut base_string = String::from("n n n");
+    /// let mut counter = 0;
+    ///
+    /// // The base string will be "1 2 3"
+    /// replace_with(&mut base_string, &"n", |_| {
+    ///     counter += 1;
+    ///     counter.to_string()
+    /// });
+    /// ```
+    pub fn replace_with<S, F>(base: &mut String, find: &S, mut replacer: F)
+        where S: ToString, F: FnMut(&str) -> String
+    {
+        let find = find.to_string();
+        if find.is_empty() {
+            return;
+        }
+        let mut replaced_string = String::with_capacity(base.len());
         let mut current_base_pos = 0usize;
-        while current_base_pos < haystack.len() {
-            let mut current_find_pos = 0usize;
-            let mut base_pos_test = current_base_pos;
-            'inner: while current_find_pos < needle.len() && base_pos_test < haystack.len() {
-                if &haystack[base_pos_test] == &needle[current_find_pos] {
-                    if current_find_pos == needle.len() - 1 {
-                        return true;
-                    }
-                    current_find_pos = current_find_pos + 1;
-                    base_pos_test = base_pos_test + 1;
-                    continue 'inner;
+        while let Some(found) = base[current_base_pos..].find(find.as_str()) {
+            let match_start = current_base_pos + found;
+            let match_end = match_start + find.len();
+            replaced_string.push_str(&base[current_base_pos..match_start]);
+            replaced_string.push_str(replacer(&base[match_start..match_end]).as_ref());
+            current_base_pos = match_end;
+        }
+        replaced_string.push_str(&base[current_base_pos..]);
+        *base = replaced_string;
+    }
+
+    /// # Description
+    ///
+    /// Toggles the case of every ASCII letter in the base string in place, uppercase becomes lowercase and lowercase becomes uppercase.
+    /// Everything else, including non-ASCII chars, is left unchanged, so the string stays valid UTF-8 and keeps its length.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::swap_case;
+    ///
+    /// let mut base_string = String::from("Hello World");
+    ///
+    /// // The base string will be "hELLO wORLD"
+    /// swap_case(&mut base_string);
+    /// ```
+    pub fn swap_case(base: &mut String) {
+        for pos in 0..base.len() {
+            let byte = base.as_bytes()[pos];
+            if byte.is_ascii_uppercase() {
+                base[pos..pos + 1].make_ascii_lowercase();
+            } else if byte.is_ascii_lowercase() {
+                base[pos..pos + 1].make_ascii_uppercase();
+            }
+        }
+    }
+
+    /// # Description
+    ///
+    /// Prepends a prefix to the base string unless the base string already starts with it, so calling it twice has no further effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `prefix` - The prefix the base string should start with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::ensure_prefix;
+    ///
+    /// let mut base_string = String::from("example.com");
+    ///
+    /// // The base string will be "https://example.com"
+    /// ensure_prefix(&mut base_string, &"https://");
+    /// ```
+    pub fn ensure_prefix<S>(base: &mut String, prefix: &S)
+        where S: ToString
+    {
+        let prefix = prefix.to_string();
+        if !base.starts_with(prefix.as_str()) {
+            base.insert_str(0, prefix.as_str());
+        }
+    }
+
+    /// # Description
+    ///
+    /// Appends a suffix to the base string unless the base string already ends with it, e.g. to make sure a directory path ends with `/`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `suffix` - The suffix the base string should end with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::ensure_suffix;
+    ///
+    /// let mut base_string = String::from("dir");
+    ///
+    /// // The base string will be "dir/"
+    /// ensure_suffix(&mut base_string, &"/");
+    /// ```
+    pub fn ensure_suffix<S>(base: &mut String, suffix: &S)
+        where S: ToString
+    {
+        let suffix = suffix.to_string();
+        if !base.ends_with(suffix.as_str()) {
+            base.push_str(suffix.as_str());
+        }
+    }
+
+    /// # Description
+    ///
+    /// Shortens the base string to at most `max_chars` chars, replacing the cut off end with `ellipsis`, the usual "Lorem ip…" UI truncation.
+    /// The base string is cut on a char boundary in place and a base string that is already short enough is left unchanged.
+    /// If `ellipsis` itself is longer than `max_chars` the base string is replaced by just the ellipsis.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `max_chars` - The maximum length in chars, including the ellipsis.
+    /// * `ellipsis` - The marker appended when the base string is cut.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::truncate_ellipsis;
+    ///
+    /// let mut base_string = String::from("Hello World");
+    ///
+    /// // The base string will be "Hello W…"
+    /// truncate_ellipsis(&mut base_string, 8, "…");
+    /// ```
+    pub fn truncate_ellipsis(base: &mut String, max_chars: usize, ellipsis: &str) {
+        if base.chars().count() <= max_chars {
+            return;
+        }
+        let keep = max_chars.saturating_sub(ellipsis.chars().count());
+        let cut = base.char_indices().nth(keep).map_or(base.len(), |(pos, _)| pos);
+        base.truncate(cut);
+        base.push_str(ellipsis);
+    }
+
+    /// # Description
+    ///
+    /// Inserts a prefix at the start of every line of the base string, e.g. to nest a generated block inside another one.
+    /// Empty lines are left empty so the result has no trailing whitespace, which also means a trailing `\n` does not get a prefix after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `prefix` - The text inserted at the start of every non-empty line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::indent;
+    ///
+    /// let mut base_string = String::from("a\nb");
+    ///
+    /// // The base string will be "  a\n  b"
+    /// indent(&mut base_string, "  ");
+    /// ```
+    pub fn indent(base: &mut String, prefix: &str) {
+        let mut indented = String::with_capacity(base.len() + prefix.len() * (base.matches('\n').count() + 1));
+        for line in base.split_inclusive('\n') {
+            if line != "\n" && line != "\r\n" {
+                indented.push_str(prefix);
+            }
+            indented.push_str(line);
+        }
+        *base = indented;
+    }
+
+    /// # Description
+    ///
+    /// Removes the longest leading whitespace common to every non-blank line of the base string, like Python's `textwrap.dedent`.
+    /// Useful for cleaning up indented multiline literals. Blank lines are ignored when finding the common whitespace and end up empty.
+    /// Tabs and spaces are not considered equal, so lines indented with a mix of both only lose the part they share.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::dedent;
+    ///
+    /// let mut base_string = String::from("    a\n      b");
+    ///
+    /// // The base string will be "a\n  b"
+    /// dedent(&mut base_string);
+    /// ```
+    pub fn dedent(base: &mut String) {
+        let mut common: Option<&str> = None;
+        for line in base.lines().filter(|line| !line.trim().is_empty()) {
+            let whitespace = &line[..line.len() - line.trim_start().len()];
+            common = Some(match common {
+                None => whitespace,
+                Some(common) => {
+                    let shared = common.char_indices()
+                        .zip(whitespace.chars())
+                        .find(|((_, a), b)| a != b)
+                        .map_or(common.len().min(whitespace.len()), |((pos, _), _)| pos);
+                    &common[..shared]
+                }
+            });
+        }
+        let common_len = common.map_or(0, str::len);
+
+        let mut dedented = String::with_capacity(base.len());
+        for line in base.split_inclusive('\n') {
+            match line.trim().is_empty() {
+                true => dedented.push_str(&line[line.trim_end_matches(['\r', '\n']).len()..]),
+                false => dedented.push_str(&line[common_len..])
+            }
+        }
+        *base = dedented;
+    }
+
+    /// # Description
+    ///
+    /// Maps individual chars of the base string to other chars in a single pass, like `tr`. Useful for transliteration and normalization.
+    /// When every char in the map is ASCII the base string is edited in place through a 128 entry lookup table,
+    /// otherwise the string is rebuilt because a replacement can have a different encoded length. If a char is mapped more than once the first mapping is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `map` - Pairs of the char being replaced and its replacement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::replace_chars;
+    ///
+    /// let mut base_string = String::from("abc");
+    ///
+    /// // The base string will be "ABc"
+    /// replace_chars(&mut base_string, &[('a', 'A'), ('b', 'B')]);
+    /// ```
+    pub fn replace_chars(base: &mut String, map: &[(char, char)]) {
+        if map.iter().all(|(from, to)| from.is_ascii() && to.is_ascii()) {
+            let mut table: [u8; 128] = core::array::from_fn(|byte| byte as u8);
+            for (from, to) in map.iter().rev() {
+                table[*from as usize] = *to as u8;
+            }
+            // Only ASCII bytes are swapped for other ASCII bytes, so the string stays valid UTF-8.
+            for byte in unsafe { base.as_bytes_mut() } {
+                if byte.is_ascii() {
+                    *byte = table[*byte as usize];
+                }
+            }
+            return;
+        }
+
+        let mut replaced = String::with_capacity(base.len());
+        for c in base.chars() {
+            match map.iter().find(|(from, _)| *from == c) {
+                Some((_, to)) => replaced.push(*to),
+                None => replaced.push(c)
+            }
+        }
+        *base = replaced;
+    }
+
+    /// # Description
+    ///
+    /// Maps each char in `from` to the char at the same index in `to`, the classic `tr` operation, e.g. `tr ab 12`.
+    /// This is `replace_chars` with the mapping split into two lists, so the same in place ASCII fast path applies, and a char listed more than once uses its first mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `from` - The chars being replaced.
+    /// * `to` - The replacement for each char of `from`. It must be the same length as `from`, or this function panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::translate;
+    ///
+    /// let mut base_string = String::from("abc");
+    ///
+    /// // The base string will be "12c"
+    /// translate(&mut base_string, &['a', 'b'], &['1', '2']);
+    /// ```
+    pub fn translate(base: &mut String, from: &[char], to: &[char]) {
+        assert_eq!(from.len(), to.len(), "translate needs as many `to` chars as `from` chars");
+        let map: Vec<(char, char)> = from.iter().copied().zip(to.iter().copied()).collect();
+        replace_chars(base, &map);
+    }
+
+    /// # Description
+    ///
+    /// Collapses every run of a repeated char from `set` down to a single instance of that char, like `tr -s`.
+    /// Only repeats of the same char are collapsed, so with `['-', '_']` the string `"a--__b"` becomes `"a-_b"`. Edits the base string in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `set` - The chars whose runs are collapsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::squeeze;
+    ///
+    /// let mut base_string = String::from("a   b");
+    ///
+    /// // The base string will be "a b"
+    /// squeeze(&mut base_string, &[' ']);
+    /// ```
+    pub fn squeeze(base: &mut String, set: &[char]) {
+        let mut previous: Option<char> = None;
+        base.retain(|c| {
+            let repeated = previous == Some(c) && set.contains(&c);
+            previous = Some(c);
+            !repeated
+        });
+    }
+
+    /// # Description
+    ///
+    /// Inserts a `\n` after every `n` chars of the base string, e.g. to wrap Base64 or hex output to a fixed column width.
+    /// Chars are counted rather than bytes so multibyte chars are never split. A newline follows every complete group of `n` chars,
+    /// so the result only ends with a newline when the length is an exact multiple of `n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `n` - The number of chars on each line. Must be greater than 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::chunk_with_newlines;
+    ///
+    /// let mut base_string = String::from("0123456789");
+    ///
+    /// // The base string will be "0123\n4567\n89"
+    /// chunk_with_newlines(&mut base_string, 4);
+    /// ```
+    pub fn chunk_with_newlines(base: &mut String, n: usize) {
+        assert!(n > 0);
+        let mut chunked = String::with_capacity(base.len() + base.len() / n);
+        for (index, c) in base.chars().enumerate() {
+            chunked.push(c);
+            if (index + 1).is_multiple_of(n) {
+                chunked.push('\n');
+            }
+        }
+        *base = chunked;
+    }
+
+    /// # Description
+    ///
+    /// Replaces every occurrence of `find`, matched case-insensitively, with `replace` adjusted to mirror the case of the matched text,
+    /// the "smart case" of an editor's find and replace. Matches are found char by char from left to right without overlapping. The case heuristic is:
+    ///
+    /// * if every cased char of the match is uppercase (`"DOG"`), the replacement is uppercased,
+    /// * otherwise if the match starts with an uppercase char (`"Dog"`), the replacement gets an uppercase first char and the rest lowercased,
+    /// * otherwise (`"dog"`, `"dOG"`) the replacement is inserted as given.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `find` - The sub-string being replaced, in any case.
+    /// * `replace` - The replacement, written as it should appear for a lowercase match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::replace_smart_case;
+    ///
+    /// let mut base_string = String::from("Dog, dog and DOG");
+    ///
+    /// // The base string will be "Cat, cat and CAT"
+    /// replace_smart_case(&mut base_string, &"dog", &"cat");
+    /// ```
+    pub fn replace_smart_case<S, R>(base: &mut String, find: &S, replace: &R)
+        where S: ToString, R: ToString
+    {
+        let find: Vec<char> = find.to_string().chars().collect();
+        if find.is_empty() {
+            return;
+        }
+        let replace = replace.to_string();
+        let chars: Vec<(usize, char)> = base.char_indices().collect();
+        let mut replaced = String::with_capacity(base.len());
+        let mut copied_to = 0usize;
+        let mut index = 0usize;
+
+        while index + find.len() <= chars.len() {
+            let is_match = chars[index..index + find.len()].iter()
+                .zip(&find)
+                .all(|(&(_, a), b)| a == *b || a.to_lowercase().eq(b.to_lowercase()));
+            if !is_match {
+                index += 1;
+                continue;
+            }
+            let start = chars[index].0;
+            let end = chars.get(index + find.len()).map_or(base.len(), |&(pos, _)| pos);
+            let matched = &base[start..end];
+            replaced.push_str(&base[copied_to..start]);
+
+            let all_upper = matched.chars().any(char::is_uppercase)
+                && matched.chars().all(|c| !c.is_lowercase());
+            let title = matched.chars().next().is_some_and(char::is_uppercase);
+            match (all_upper, title) {
+                (true, _) => replaced.push_str(replace.to_uppercase().as_str()),
+                (false, true) => {
+                    let mut replace_chars = replace.chars();
+                    if let Some(first) = replace_chars.next() {
+                        replaced.extend(first.to_uppercase());
+                        replaced.push_str(replace_chars.as_str().to_lowercase().as_str());
+                    }
+                }
+                (false, false) => replaced.push_str(replace.as_str())
+            }
+            copied_to = end;
+            index += find.len();
+        }
+        replaced.push_str(&base[copied_to..]);
+        *base = replaced;
+    }
+
+    /// # Description
+    ///
+    /// Shifts every ASCII letter of the base string `shift` places through the alphabet, wrapping around within `A-Z` and `a-z` (a Caesar cipher).
+    /// Negative shifts go backwards. Everything else, including non-ASCII chars, is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `shift` - The number of places each letter is moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::caesar_shift;
+    ///
+    /// let mut base_string = String::from("xyz ABC");
+    ///
+    /// // The base string will be "abc DEF"
+    /// caesar_shift(&mut base_string, 3);
+    /// ```
+    pub fn caesar_shift(base: &mut String, shift: i32) {
+        let shift = shift.rem_euclid(26) as u8;
+        *base = base.chars()
+            .map(|c| {
+                let first = match c {
+                    'a'..='z' => b'a',
+                    'A'..='Z' => b'A',
+                    _ => return c
+                };
+                (first + (c as u8 - first + shift) % 26) as char
+            })
+            .collect();
+    }
+
+    /// # Description
+    ///
+    /// Applies ROT13 to the base string, a `caesar_shift` of 13. Applying it twice gives back the original string.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::rot13;
+    ///
+    /// let mut base_string = String::from("Hello");
+    ///
+    /// // The base string will be "Uryyb"
+    /// rot13(&mut base_string);
+    /// ```
+    pub fn rot13(base: &mut String) {
+        caesar_shift(base, 13);
+    }
+
+    /// # Description
+    ///
+    /// Replaces the content between each `open` and `close` delimiter pair with the replacement, e.g. to redact templated regions like `{{ ... }}`.
+    /// The replacement is exclusive, so the delimiters themselves are kept. Pairs are matched left to right without nesting,
+    /// and an `open` with no `close` after it is left as-is, as is everything when either delimiter is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    /// * `open` - The delimiter that starts a region.
+    /// * `close` - The delimiter that ends a region.
+    /// * `replacement` - The string that replaces the content of every region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::replace_between;
+    ///
+    /// let mut base_string = String::from("Hello {{ name }}!");
+    ///
+    /// // The base string will be "Hello {{***}}!"
+    /// replace_between(&mut base_string, "{{", "}}", &"***");
+    /// ```
+    pub fn replace_between<R>(base: &mut String, open: &str, close: &str, replacement: &R)
+        where R: ToString
+    {
+        if open.is_empty() || close.is_empty() {
+            return;
+        }
+        let replacement = replacement.to_string();
+        let mut replaced_string = String::with_capacity(base.len());
+        let mut current_base_pos = 0usize;
+        while let Some(open_pos) = base[current_base_pos..].find(open) {
+            let content_start = current_base_pos + open_pos + open.len();
+            let content_end = match base[content_start..].find(close) {
+                Some(close_pos) => content_start + close_pos,
+                None => break
+            };
+            replaced_string.push_str(&base[current_base_pos..content_start]);
+            replaced_string.push_str(&replacement);
+            replaced_string.push_str(close);
+            current_base_pos = content_end + close.len();
+        }
+        replaced_string.push_str(&base[current_base_pos..]);
+        *base = replaced_string;
+    }
+
+    /// # Description
+    ///
+    /// Collapses consecutive repeats of the same whitespace delimited word into one, e.g. to clean up typos like `"the the cat"`.
+    /// Words are compared case-sensitively, so `"The the"` is left alone. The remaining words are joined with a single space,
+    /// which also collapses runs of whitespace and trims the ends of the base string.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string that will be modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::modify::dedup_words;
+    ///
+    /// let mut base_string = String::from("the the cat");
+    ///
+    /// // The base string will be "the cat"
+    /// dedup_words(&mut base_string);
+    /// ```
+    pub fn dedup_words(base: &mut String) {
+        let mut deduped = String::with_capacity(base.len());
+        let mut previous: Option<&str> = None;
+        for word in base.split_whitespace() {
+            if previous == Some(word) {
+                continue;
+            }
+            if previous.is_some() {
+                deduped.push(' ');
+            }
+            deduped.push_str(word);
+            previous = Some(word);
+        }
+        *base = deduped;
+    }
+}
+
+
+pub mod compare {
+    use alloc::collections::{BTreeMap, BTreeSet};
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::ops::ControlFlow;
+    use core::simd::{Simd, u8x64};
+    use core::simd::cmp::SimdPartialEq;
+    use core::simd::num::{SimdInt, SimdUint};
+    #[cfg(feature = "std")]
+    use std::collections::{HashMap, HashSet};
+    #[cfg(feature = "unicode")]
+    use unicode_segmentation::UnicodeSegmentation;
+
+
+    /// # Description
+    /// A 256 bit set of bytes with constant time membership checks.
+    /// The functions taking a group of chars build one of these once when every char in the group is ASCII,
+    /// instead of scanning the whole group for every byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::AsciiCharSet;
+    ///
+    /// let char_set = AsciiCharSet::from_chars(&['a', 'b', 'c']).unwrap();
+    ///
+    /// // contains = true
+    /// let contains = char_set.contains(b'a');
+    /// ```
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct AsciiCharSet {
+        bits: [u64; 4]
+    }
+
+    impl AsciiCharSet {
+        pub fn new() -> Self {
+            AsciiCharSet {
+                bits: [0; 4]
+            }
+        }
+
+        /// # Description
+        /// Builds a set from a group of chars. Returns `None` if any of the chars is not ASCII.
+        pub fn from_chars(chars: &[char]) -> Option<Self> {
+            let mut char_set = AsciiCharSet::new();
+            for c_char in chars {
+                if !c_char.is_ascii() {
+                    return None;
+                }
+                char_set.insert(*c_char as u8);
+            }
+            Some(char_set)
+        }
+
+        pub fn insert(&mut self, byte: u8) {
+            self.bits[(byte >> 6) as usize] |= 1u64 << (byte & 63);
+        }
+
+        pub fn contains(&self, byte: u8) -> bool {
+            self.bits[(byte >> 6) as usize] & (1u64 << (byte & 63)) != 0
+        }
+    }
+
+    /// # Description
+    ///
+    /// Finds all substrings containing a set of specified characters.
+    /// Returns a HashMap containing all substrings as keys and the value being the number of times the substring occurs in the base string.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `char_group` - the specified chars being looked for in substrings.
+    ///
+    /// # Outputs
+    ///
+    /// * HashMap<String, usize> - HashMap of all strings as keys and the number of times the substring occurs as the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::substring_char_group_count;
+    ///
+    /// let str1 = String::from("abcc");
+    /// let chars = vec!['a', 'b', 'c'];
+    ///
+    /// // The result will look like this: {'abc': 1, 'abcc': 1}
+    /// let result = substring_char_group_count(&str1, &chars);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn substring_char_group_count<B>(
+        base: &B,
+        char_group: &Vec<char>
+    ) -> HashMap<String, usize>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let haystack = binding.as_bytes();
+        let char_set = AsciiCharSet::from_chars(char_group);
+        let mut base_pos = 0usize;
+        let mut sub_string_count: HashMap<String, usize> = HashMap::new();
+        let mut sub_byte_count: HashMap<&[u8], usize> = HashMap::new();
+
+        while base_pos < haystack.len() {
+            let mut end_pos = haystack.len();
+            let current = base_pos;
+
+            while current < end_pos {
+                let mut found_count_down = char_group.len();
+
+                match &char_set {
+                    Some(char_set) => {
+                        let mut found = AsciiCharSet::new();
+                        for byte in &haystack[current..end_pos] {
+                            if char_set.contains(*byte) {
+                                found.insert(*byte);
+                            }
+                        }
+                        if &found == char_set {
+                            found_count_down = 0;
+                        }
+                    }
+                    None => {
+                        for char in char_group {
+                            let byte = *char as u8;
+                            if haystack[current..end_pos].contains(&byte) {
+                                found_count_down -= 1
+                            }
+                        }
+                    }
+                }
+
+                match (found_count_down == 0, sub_byte_count.get(&haystack[current..end_pos])) {
+                    (true, Some(count)) => sub_byte_count.insert(&haystack[current..end_pos], count + 1usize),
+                    (true, None) => sub_byte_count.insert(&haystack[current..end_pos], 1usize),
+                    _ => None
+                };
+                end_pos -= 1;
+            }
+            base_pos += 1;
+        }
+
+        for (byte_arr, count) in sub_byte_count {
+            sub_string_count.insert(unsafe { core::str::from_utf8_unchecked(byte_arr).to_string() }, count);
+        }
+        sub_string_count
+    }
+
+    /// # Description
+    ///
+    /// Gets the count of all characters fom a provided base string given a group of characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `chars` - A vector of characters being searched and tallied.
+    ///
+    /// # Output
+    ///
+    /// * HashMap<char, u32> - A hashmap containing the different characters and the number of times they appear in the base string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::count_chars;
+    ///
+    /// let str1 = String::from("abc");
+    /// let chars = vec!['a', 'b', 'c'];
+    ///
+    /// // The result will look like this: {'a': 1, 'b': 1, 'c': 1}
+    /// let result = count_chars(&str1, &chars);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn count_chars<B>(
+        base: &B,
+        chars: &Vec<char>
+    ) -> HashMap<char, usize>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let bytes = binding.as_bytes();
+        let mut char_count: HashMap<char, usize> = HashMap::new();
+        if let Some(char_set) = AsciiCharSet::from_chars(chars) {
+            let mut byte_count = [0usize; 256];
+            for byte in bytes {
+                if char_set.contains(*byte) {
+                    byte_count[*byte as usize] += 1;
+                }
+            }
+            for c_char in chars {
+                char_count.insert(*c_char, byte_count[*c_char as usize]);
+            }
+            return char_count;
+        }
+
+        for c_char in chars {
+            let mut pos = 0usize;
+            let mut count = 0usize;
+            while pos < bytes.len() {
+                if c_char == &(bytes[pos] as char) {
+                    count += 1;
+                }
+                pos += 1;
+            }
+            char_count.insert(*c_char, count);
+        }
+
+        char_count
+    }
+
+    #[cfg(feature = "std")]
+    pub fn count_chars_simd<B>(
+        base: &B,
+        chars: &Vec<char>
+    ) -> HashMap<char, usize>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let haystack = binding.as_bytes();
+        let mut needles: HashMap<char, usize> = HashMap::new();
+        for c_char in chars {
+            let needle = *c_char as u8;
+            let count = needle_count_simd(haystack, needle);
+            needles.insert(*c_char, count);
+        }
+        needles
+    }
+
+    fn simd_u8x64_from_offset(slice: &[u8], offset: usize) -> u8x64 {
+        let slice = unsafe { slice.get_unchecked(offset..) };
+        if slice.len() < 64 {
+            let mut padded_slice: [u8; 64] = [0; 64];
+            copy_to_arr_with_padding(&mut padded_slice, slice);
+            u8x64::from_slice(padded_slice.as_slice())
+        } else {
+            u8x64::from_slice(slice)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn simd_sum_x64(u8s: &u8x64) -> usize {
+        let mut store = [0; size_of::<u8x64>()];
+        u8s.copy_to_slice(&mut store);
+        store.iter().map(|&e| e as usize).sum()
+    }
+
+    fn copy_to_arr_with_padding(dest: &mut [u8], src: &[u8]) {
+        if dest.len() == src.len() {
+            dest.copy_from_slice(src);
+        } else if dest.len() > src.len() {
+            dest[..src.len()].copy_from_slice(src);
+        } else {
+            dest.copy_from_slice(&src[..dest.len()]);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn needle_count_simd(haystack: &[u8], needle: u8) -> usize {
+        let needle = u8x64::splat(needle);
+        let loops = haystack.len() / 64 + 1;
+        let mut counts = u8x64::splat(0);
+        let mut offset = 0;
+        for _ in 0..loops {
+            let res = simd_u8x64_from_offset(haystack, offset).simd_eq(needle).to_int().cast();
+            counts -= u8x64::from(res);
+            offset += 64;
+        }
+        simd_sum_x64(&counts)
+    }
+
+    #[cfg(feature = "simd")]
+    fn next_byte_position_simd(haystack: &[u8], from: usize, byte: u8) -> Option<usize> {
+        let needle = u8x64::splat(byte);
+        let mut offset = from;
+        while offset < haystack.len() {
+            let valid = match haystack.len() - offset {
+                remaining if remaining < 64 => (1u64 << remaining) - 1,
+                _ => u64::MAX
+            };
+            let found = simd_u8x64_from_offset(haystack, offset).simd_eq(needle).to_bitmask() & valid;
+            if found != 0 {
+                return Some(offset + found.trailing_zeros() as usize);
+            }
+            offset += 64;
+        }
+        None
+    }
+
+    /// # Description
+    ///
+    /// Finds all sub-string occurrences and ranges the sub-strings occur at.
+    /// All provided arguments are assumed to be valid UTF-8 chars.
+    ///
+    /// # Arguments
+    /// * `base` - The base string we are searching.
+    /// * `find` - The sub-string we are trying to find all occurrences of.
+    ///
+    /// # Output
+    /// * `Vec<(usize, usize)>` - A vector of tuples containing 2 usize numbers. The first number is the starting position of the occurrence, the second number is where the occurrence ends. The vector will be empty if no occurrences were found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::find_all_exact;
+    ///
+    /// let base_string = String::from("This is my test string! test test!");
+    /// let find_string = String::from("test");
+    ///
+    /// // output in this case will look like this: [(11, 15), (24, 28), (29, 33)]
+    /// let result = find_all_exact(&base_string, &find_string);
+    /// ```
+    pub fn find_all_exact<B, S>(
+        base: &B,
+        find: &S
+    ) -> Vec<(usize, usize)>
+        where B: ToString, S: ToString
+    {
+        let (base, find) = (base.to_string(), find.to_string());
+        assert!(base.len() >= find.len());
+        let mut matches: Vec<(usize, usize)> = vec![];
+        for_each_match_bytes(base.as_bytes(), find.as_bytes(), |start, end| {
+            matches.push((start, end));
+            ControlFlow::Continue(())
+        });
+        matches
+    }
+
+    /// # Description
+    ///
+    /// Calls `f` with the range of every sub-string occurrence, in order, without collecting them.
+    /// Scanning stops as soon as `f` returns `ControlFlow::Break`. Occurrences may overlap, the same as `find_all_exact`.
+    /// All provided arguments are assumed to be valid UTF-8 chars.
+    ///
+    /// # Arguments
+    /// * `base` - The base string we are searching.
+    /// * `find` - The sub-string we are trying to find all occurrences of.
+    /// * `f` - Called with the start and end position of each occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ops::ControlFlow;
+    /// use string_simple::compare::for_each_match;
+    ///
+    /// let mut first_two = vec![];
+    ///
+    /// // first_two will look like this: [(0, 4), (5, 9)]
+    /// for_each_match(&"test test test", &"test", |start, end| {
+    ///     first_two.push((start, end));
+    ///     match first_two.len() {
+    ///         2 => ControlFlow::Break(()),
+    ///         _ => ControlFlow::Continue(())
+    ///     }
+    /// });
+    /// ```
+    pub fn for_each_match<B, S, F>(
+        base: &B,
+        find: &S,
+        f: F
+    )
+        where B: ToString, S: ToString, F: FnMut(usize, usize) -> ControlFlow<()>
+    {
+        let t = base.to_string();
+        let base_string_bytes = t.as_bytes();
+        let t = find.to_string();
+        let find_string_bytes = t.as_bytes();
+        for_each_match_bytes(base_string_bytes, find_string_bytes, f);
+    }
+
+    fn for_each_match_bytes<F>(base_string_bytes: &[u8], find_string_bytes: &[u8], mut f: F)
+        where F: FnMut(usize, usize) -> ControlFlow<()>
+    {
+        let mut current_base_pos = 0usize;
+        while current_base_pos < base_string_bytes.len() {
+            let mut current_find_pos = 0usize;
+            let mut current_base_test = current_base_pos;
+            'inner: while current_find_pos < find_string_bytes.len()
+                && current_base_test < base_string_bytes.len() {
+                match (&base_string_bytes[current_base_test] == &find_string_bytes[current_find_pos],
+                       current_find_pos == find_string_bytes.len() - 1) {
+                    (true, true) => {
+                        if f(current_base_pos, current_base_test + 1).is_break() {
+                            return;
+                        }
+                        break 'inner;
+                    }
+                    (true, false) => {
+                        current_find_pos += 1;
+                        current_base_test += 1;
+                        continue 'inner;
+                    }
+                    (_, _) => break 'inner
+                }
+            }
+            current_base_pos += 1;
+        }
+    }
+
+    /// # Description
+    /// Find the first occurrence of a sub-string within a base string.
+    /// All arguments are assumed to be valid UTF-8 characters.
+    /// The search itself is done by `contains_bytes`. With the `simd` feature enabled candidate start positions are found 64 bytes at a time
+    /// by comparing against the first byte of `needle`, and only those candidates are verified byte by byte.
+    ///
+    /// # Arguments
+    /// * `base` - The provided base string we are searching.
+    /// * `find` - The substring we are trying to find.
+    ///
+    /// # Output
+    /// * `Option<(usize, usize)>` - Optional tuple containing the start and end positions in the `base`  where the first `find` can be found. Returns `None` if the `find` was not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::contains;
+    /// let base_string = String::from("This is my test string! test test!");
+    /// let find_string = String::from("test");
+    ///
+    /// // output in this case will look like this: Some((11, 15))
+    /// let result = contains(&base_string, &find_string);
+    /// ```
+    pub fn contains<B, S>(
+        haystack: &B,
+        needle: &S
+    ) -> bool
+        where B: ToString, S: ToString
+    {
+        let t = haystack.to_string();
+        let haystack = t.as_bytes();
+        let t = needle.to_string();
+        let needle = t.as_bytes();
+        assert!(haystack.len() >= needle.len());
+        contains_bytes(haystack, needle)
+    }
+
+    pub fn contains_simd<B, S>(haystack: &B, needle: &S) -> bool
+        where
+            B: ToString,
+            S: ToString,
+    {
+        let haystack = haystack.to_string().as_bytes();
+        let needle = needle.to_string().as_bytes();
+
+        // Ensure preconditions are met: haystack must be larger than needle and needle non-empty
+        assert!(haystack.len() >= needle.len() && !needle.is_empty());
+
+        let needle_len = needle.len();
+        let haystack_len = haystack.len();
+        let loops = (haystack_len + 63) / 64; // Calculate the number of 64-byte chunks
+        let first_byte = needle[0]; // First byte of the needle for initial comparison
+
+        for loop_index in 0..loops {
+            let offset = loop_index * 64;
+
+            // Fetch 64 bytes from the haystack, or fewer if near the end
+            let chunk = simd_u8x64_from_offset(haystack, offset);
+            let compare_mask: Simd<u8, 64> = chunk.simd_eq(u8x64::splat(first_byte)).to_int().cast();
+
+            // If no matches for the first byte, skip to the next chunk
+            if compare_mask.reduce_max() == 0 {
+                continue;
+            }
+
+            // Check subsequent bytes of the needle
+            for i in 0..64.min(haystack_len - offset) {
+                if i + needle_len > haystack_len {
+                    break; // Not enough remaining bytes in the haystack
+                }
+
+                if haystack[offset + i..offset + i + needle_len] == needle[..] {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+
+    /// # Description
+    ///
+    /// Counts the non-overlapping occurrences of a sub-string, scanning left to right a byte at a time.
+    /// After a match the scan continues after the end of the match, so `"aaaa"` contains `"aa"` twice.
+    /// This is the reference definition `substring_count_simd` is tested against.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The base string being searched.
+    /// * `needle` - The sub-string being counted. Must not be empty or longer than `haystack`.
+    ///
+    /// # Output
+    ///
+    /// * `usize` - The number of non-overlapping occurrences of `needle`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::substring_count_scalar;
+    ///
+    /// // result = 2
+    /// let result = substring_count_scalar(&"aaaa", &"aa");
+    /// ```
+    pub fn substring_count_scalar<B, S>(haystack: &B, needle: &S) -> usize
+        where B: ToString, S: ToString
+    {
+        let binding = haystack.to_string();
+        let haystack = binding.as_bytes();
+        let binding = needle.to_string();
+        let needle = binding.as_bytes();
+
+        assert!(haystack.len() >= needle.len() && !needle.is_empty());
+
+        let mut count = 0usize;
+        let mut pos = 0usize;
+        while pos + needle.len() <= haystack.len() {
+            if &haystack[pos..pos + needle.len()] == needle {
+                count += 1;
+                pos += needle.len();
+            } else {
+                pos += 1;
+            }
+        }
+        count
+    }
+
+    /// # Description
+    ///
+    /// Counts the non-overlapping occurrences of a sub-string with the Knuth-Morris-Pratt algorithm, giving the same result as `substring_count_scalar`.
+    /// A table of the needle's borders lets the scan continue after a mismatch without re-reading the haystack,
+    /// so the run time is linear even for inputs like `"aaa...a"` against `"aaab"`, and it does not depend on the `simd` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The base string being searched.
+    /// * `needle` - The sub-string being counted. An empty needle or one longer than `haystack` has no occurrences.
+    ///
+    /// # Output
+    ///
+    /// * `usize` - The number of non-overlapping occurrences of `needle`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::substring_count_kmp;
+    ///
+    /// // result = 2
+    /// let result = substring_count_kmp(&"abababa", &"aba");
+    /// ```
+    pub fn substring_count_kmp<B, S>(haystack: &B, needle: &S) -> usize
+        where B: ToString, S: ToString
+    {
+        let binding = haystack.to_string();
+        let haystack = binding.as_bytes();
+        let binding = needle.to_string();
+        let needle = binding.as_bytes();
+        if needle.is_empty() || needle.len() > haystack.len() {
+            return 0;
+        }
+
+        // borders[i] is the length of the longest proper prefix of needle[..=i] that is also its suffix.
+        let mut borders = vec![0usize; needle.len()];
+        let mut matched = 0usize;
+        for i in 1..needle.len() {
+            while matched > 0 && needle[i] != needle[matched] {
+                matched = borders[matched - 1];
+            }
+            if needle[i] == needle[matched] {
+                matched += 1;
+            }
+            borders[i] = matched;
+        }
+
+        let mut count = 0usize;
+        matched = 0;
+        for byte in haystack {
+            while matched > 0 && *byte != needle[matched] {
+                matched = borders[matched - 1];
+            }
+            if *byte == needle[matched] {
+                matched += 1;
+            }
+            if matched == needle.len() {
+                count += 1;
+                matched = 0;
+            }
+        }
+        count
+    }
+
+    /// # Description
+    ///
+    /// Counts the non-overlapping occurrences of a sub-string 64 bytes at a time, giving the same result as `substring_count_scalar`.
+    /// For every chunk the lanes matching the first byte of the needle are narrowed down by comparing each following needle byte at the next offset,
+    /// then the remaining match starts are taken left to right, skipping any that overlap the previous match (also across chunk boundaries).
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The base string being searched.
+    /// * `needle` - The sub-string being counted. Must not be empty or longer than `haystack`.
+    ///
+    /// # Output
+    ///
+    /// * `usize` - The number of non-overlapping occurrences of `needle`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::substring_count_simd;
+    ///
+    /// // result = 2
+    /// let result = substring_count_simd(&"aaaa", &"aa");
+    /// ```
+    pub fn substring_count_simd<B, S>(haystack: &B, needle: &S) -> usize
+        where B: ToString, S: ToString
+    {
+        let binding = haystack.to_string();
+        let haystack = binding.as_bytes();
+        let binding = needle.to_string();
+        let needle = binding.as_bytes();
+
+        assert!(haystack.len() >= needle.len() && !needle.is_empty());
+
+        let last_start = haystack.len() - needle.len();
+        let mut count = 0usize;
+        let mut next_start = 0usize;
+        let mut offset = 0usize;
+
+        while offset <= last_start {
+            let valid = match last_start + 1 - offset {
+                remaining if remaining < 64 => (1u64 << remaining) - 1,
+                _ => u64::MAX
+            };
+            let mut starts = valid;
+            for (needle_pos, needle_byte) in needle.iter().enumerate() {
+                starts &= simd_u8x64_from_offset(haystack, offset + needle_pos)
+                    .simd_eq(u8x64::splat(*needle_byte))
+                    .to_bitmask();
+                if starts == 0 {
+                    break;
+                }
+            }
+            while starts != 0 {
+                let start = offset + starts.trailing_zeros() as usize;
+                if start >= next_start {
+                    count += 1;
+                    next_start = start + needle.len();
+                }
+                starts &= starts - 1;
+            }
+            offset += 64;
+        }
+        count
+    }
+
+    /// # Description
+    ///
+    /// Counts the occurrences of several substrings in a single pass over the base string using a `MultiMatcher`.
+    /// Overlapping occurrences are counted, the same as `find_all_exact`. Empty needles never match and are reported with a count of 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The base string being searched.
+    /// * `needles` - The substrings being tallied.
+    ///
+    /// # Output
+    ///
+    /// * HashMap<String, usize> - A hashmap containing each needle and the number of times it occurs in the base string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::count_many;
+    ///
+    /// let str1 = String::from("foofoobar");
+    /// let needles = vec!["foo", "bar"];
+    ///
+    /// // The result will look like this: {"foo": 2, "bar": 1}
+    /// let result = count_many(&str1, &needles);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn count_many<B, S>(
+        haystack: &B,
+        needles: &[S]
+    ) -> HashMap<String, usize>
+        where B: ToString, S: ToString
+    {
+        let binding = haystack.to_string();
+        let haystack = binding.as_bytes();
+        let mut needle_count: HashMap<String, usize> = HashMap::new();
+        let mut unique_needles: Vec<String> = vec![];
+
+        for needle in needles {
+            let needle = needle.to_string();
+            if needle_count.insert(needle.clone(), 0).is_none() {
+                unique_needles.push(needle);
+            }
+        }
+
+        let patterns: Vec<&str> = unique_needles.iter().map(|needle| needle.as_str()).collect();
+        let matcher = multi::MultiMatcher::new(&patterns);
+        let mut counts = vec![0usize; unique_needles.len()];
+        matcher.scan(haystack, |_, _, index| counts[index] += 1);
+
+        for (needle, count) in unique_needles.into_iter().zip(counts) {
+            needle_count.insert(needle, count);
+        }
+        needle_count
+    }
+
+    /// # Description
+    ///
+    /// Checks whether a string reads the same forwards and backwards.
+    /// The comparison is done over chars (Unicode scalar values), so multibyte characters are compared as a whole.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being checked.
+    ///
+    /// # Output
+    ///
+    /// * `bool` - Whether the base string is a palindrome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::is_palindrome;
+    ///
+    /// // result will be true
+    /// let result = is_palindrome(&"level");
+    /// ```
+    pub fn is_palindrome<B>(base: &B) -> bool
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let mut forwards = binding.chars();
+        let mut backwards = binding.chars().rev();
+        let mut remaining = binding.chars().count() / 2;
+        while remaining > 0 {
+            if forwards.next() != backwards.next() {
+                return false;
+            }
+            remaining -= 1;
+        }
+        true
+    }
+
+    /// # Description
+    ///
+    /// Checks whether a string reads the same forwards and backwards, skipping all non-alphanumeric chars and ignoring ASCII case.
+    /// Only ASCII case is folded, other chars must match exactly.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being checked.
+    ///
+    /// # Output
+    ///
+    /// * `bool` - Whether the base string is a palindrome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::is_palindrome_ignore;
+    ///
+    /// // result will be true
+    /// let result = is_palindrome_ignore(&"A man a plan a canal Panama");
+    /// ```
+    pub fn is_palindrome_ignore<B>(base: &B) -> bool
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let chars: Vec<char> = binding.chars()
+            .filter(|c| c.is_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        let mut front = 0usize;
+        let mut back = chars.len();
+        while front + 1 < back {
+            back -= 1;
+            if chars[front] != chars[back] {
+                return false;
+            }
+            front += 1;
+        }
+        true
+    }
+
+    /// # Description
+    ///
+    /// Finds the longest suffix shared by all provided strings. Suffixes are compared char by char, so the result is always valid UTF-8.
+    ///
+    /// # Arguments
+    ///
+    /// * `strings` - The strings being compared.
+    ///
+    /// # Output
+    ///
+    /// * `String` - The longest common suffix. Empty if `strings` is empty or the strings share no suffix. A single string is its own suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::longest_common_suffix;
+    ///
+    /// let strings = vec!["running", "jumping", "singing"];
+    ///
+    /// // result = "ing"
+    /// let result = longest_common_suffix(&strings);
+    /// ```
+    pub fn longest_common_suffix<S>(strings: &[S]) -> String
+        where S: ToString
+    {
+        let strings: Vec<String> = strings.iter().map(|s| s.to_string()).collect();
+        let first = match strings.first() {
+            Some(first) => first,
+            None => return String::new()
+        };
+
+        let mut suffix_len = first.len();
+        for other in &strings[1..] {
+            let mut common_len = 0usize;
+            for (a, b) in first.chars().rev().zip(other.chars().rev()) {
+                if a != b || common_len + a.len_utf8() > suffix_len {
+                    break;
+                }
+                common_len += a.len_utf8();
+            }
+            suffix_len = common_len;
+        }
+        first[first.len() - suffix_len..].to_string()
+    }
+
+    /// # Description
+    ///
+    /// Counts every byte value in the base string, scanning it once in 64 byte chunks.
+    /// Note that this counts bytes, not chars; a multibyte char adds one count to each of its encoded bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being counted.
+    ///
+    /// # Output
+    ///
+    /// * `[usize; 256]` - The number of times each byte value occurs, indexed by the byte value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::byte_histogram;
+    ///
+    /// let result = byte_histogram(&"aab");
+    ///
+    /// // result[b'a' as usize] = 2, result[b'b' as usize] = 1
+    /// assert_eq!(2, result[b'a' as usize]);
+    /// ```
+    pub fn byte_histogram<B>(base: &B) -> [usize; 256]
+        where B: ToString
+    {
+        bytes_histogram(base.to_string().as_bytes())
+    }
+
+    fn bytes_histogram(haystack: &[u8]) -> [usize; 256] {
+        let mut histogram = [0usize; 256];
+        let mut offset = 0usize;
+        while offset < haystack.len() {
+            let chunk = simd_u8x64_from_offset(haystack, offset).to_array();
+            let valid = (haystack.len() - offset).min(64);
+            for byte in &chunk[..valid] {
+                histogram[*byte as usize] += 1;
+            }
+            offset += 64;
+        }
+        histogram
+    }
+
+    /// # Description
+    ///
+    /// Calculates the Levenshtein edit distance between two strings, giving up as soon as the distance is known to exceed `max`.
+    /// Only the cells within `max` of the DP matrix diagonal are computed, which is much faster than the full distance when only near-matches matter.
+    /// Strings are compared by char.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first string being compared.
+    /// * `b` - The second string being compared.
+    /// * `max` - The largest distance the caller is interested in.
+    ///
+    /// # Output
+    ///
+    /// * `Option<usize>` - The edit distance if it is `<= max`, otherwise `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::levenshtein_within;
+    ///
+    /// // result = Some(3)
+    /// let result = levenshtein_within(&"kitten", &"sitting", 3);
+    /// ```
+    pub fn levenshtein_within<A, B>(
+        a: &A,
+        b: &B,
+        max: usize
+    ) -> Option<usize>
+        where A: ToString, B: ToString
+    {
+        let a: Vec<char> = a.to_string().chars().collect();
+        let b: Vec<char> = b.to_string().chars().collect();
+        if a.len().abs_diff(b.len()) > max {
+            return None;
+        }
+
+        let out_of_band = max + 1;
+        let mut prev: Vec<usize> = (0..=b.len()).map(|j| j.min(out_of_band)).collect();
+        let mut curr: Vec<usize> = vec![out_of_band; b.len() + 1];
+
+        for i in 1..=a.len() {
+            let low = i.saturating_sub(max).max(1);
+            let high = (i + max).min(b.len());
+            curr[low - 1] = if low == 1 { i.min(out_of_band) } else { out_of_band };
+            let mut row_min = curr[low - 1];
+
+            for j in low..=high {
+                let substitute = prev[j - 1] + usize::from(a[i - 1] != b[j - 1]);
+                let delete = prev[j] + 1;
+                let insert = curr[j - 1] + 1;
+                curr[j] = substitute.min(delete).min(insert).min(out_of_band);
+                row_min = row_min.min(curr[j]);
+            }
+            if high < b.len() {
+                curr[high + 1] = out_of_band;
+            }
+            if row_min > max {
+                return None;
+            }
+            core::mem::swap(&mut prev, &mut curr);
+        }
+
+        match prev[b.len()] {
+            distance if distance <= max => Some(distance),
+            _ => None
+        }
+    }
+
+    /// # Description
+    ///
+    /// Counts the words in a string, where a word is a run of bytes that are not ASCII whitespace.
+    /// Non-ASCII whitespace chars are treated as part of a word.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being counted.
+    ///
+    /// # Output
+    ///
+    /// * `usize` - The number of words in the base string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::word_count;
+    ///
+    /// // result = 3
+    /// let result = word_count(&"  one two\tthree\n");
+    /// ```
+    pub fn word_count<B>(base: &B) -> usize
+        where B: ToString
+    {
+        base.to_string().split_ascii_whitespace().count()
+    }
+
+    /// # Description
+    ///
+    /// Counts the words in a string the same way as `word_count`, classifying 64 bytes at a time with SIMD.
+    /// Every transition from ASCII whitespace to non-whitespace starts a word, and the last byte of each chunk is carried into the next chunk so words crossing a chunk edge are counted once.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being counted.
+    ///
+    /// # Output
+    ///
+    /// * `usize` - The number of words in the base string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::word_count_simd;
+    ///
+    /// // result = 3
+    /// let result = word_count_simd(&"  one two\tthree\n");
+    /// ```
+    pub fn word_count_simd<B>(base: &B) -> usize
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let haystack = binding.as_bytes();
+        let mut count = 0usize;
+        let mut previous_whitespace = 1u64;
+        let mut offset = 0usize;
+        while offset < haystack.len() {
+            let chunk = simd_u8x64_from_offset(haystack, offset);
+            let whitespace = (chunk.simd_eq(u8x64::splat(b' '))
+                | chunk.simd_eq(u8x64::splat(b'\t'))
+                | chunk.simd_eq(u8x64::splat(b'\n'))
+                | chunk.simd_eq(u8x64::splat(b'\x0C'))
+                | chunk.simd_eq(u8x64::splat(b'\r')))
+                .to_bitmask();
+            let valid = match haystack.len() - offset {
+                remaining if remaining < 64 => (1u64 << remaining) - 1,
+                _ => u64::MAX
+            };
+            let word_starts = !whitespace & ((whitespace << 1) | previous_whitespace) & valid;
+            count += word_starts.count_ones() as usize;
+            previous_whitespace = whitespace >> 63;
+            offset += 64;
+        }
+        count
+    }
+
+    /// # Description
+    ///
+    /// Checks whether any of several substrings occur in the base string.
+    /// All needles are searched for in a single shared scan using a `MultiMatcher`, which stops at the first hit.
+    /// An empty needle list never matches, while an empty needle is contained in every string and always matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The base string being searched.
+    /// * `needles` - The substrings being looked for.
+    ///
+    /// # Output
+    ///
+    /// * `bool` - Whether at least one needle was found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::contains_any;
+    ///
+    /// // result will be true
+    /// let result = contains_any(&"I have a dog", &["cat", "dog"]);
+    /// ```
+    pub fn contains_any<B, S>(
+        haystack: &B,
+        needles: &[S]
+    ) -> bool
+        where B: ToString, S: ToString
+    {
+        let needles: Vec<String> = needles.iter().map(|needle| needle.to_string()).collect();
+        if needles.iter().any(|needle| needle.is_empty()) {
+            return true;
+        }
+        let patterns: Vec<&str> = needles.iter().map(|needle| needle.as_str()).collect();
+        multi::MultiMatcher::new(&patterns).is_match(haystack)
+    }
+
+    /// # Description
+    ///
+    /// Checks whether every one of several substrings occurs in the base string, the AND counterpart to `contains_any`.
+    /// The needles are searched for one at a time and the check stops at the first missing needle.
+    /// An empty needle list always matches, as does an empty needle since it is contained in every string.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The base string being searched.
+    /// * `needles` - The substrings that must all be found.
+    ///
+    /// # Output
+    ///
+    /// * `bool` - Whether every needle was found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::contains_all;
+    ///
+    /// // result will be true
+    /// let result = contains_all(&"cat and dog", &["cat", "dog"]);
+    /// ```
+    pub fn contains_all<B, S>(
+        haystack: &B,
+        needles: &[S]
+    ) -> bool
+        where B: ToString, S: ToString
+    {
+        let binding = haystack.to_string();
+        let haystack = binding.as_bytes();
+        needles.iter().all(|needle| {
+            let needle = needle.to_string();
+            needle.is_empty() || contains_bytes(haystack, needle.as_bytes())
+        })
+    }
+
+    /// # Description
+    ///
+    /// Finds whichever of several substrings occurs earliest in the base string.
+    /// When several needles match at the same start position the one listed first in `needles` wins. Empty needles never match.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The base string being searched.
+    /// * `needles` - The substrings being looked for.
+    ///
+    /// # Output
+    ///
+    /// * `Option<(usize, usize, usize)>` - The start position, end position and index in `needles` of the earliest match. Returns `None` if no needle was found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::find_any;
+    ///
+    /// // result = Some((2, 4, 1))
+    /// let result = find_any(&"a != b", &["==", "!=", "<"]);
+    /// ```
+    pub fn find_any<B, S>(
+        haystack: &B,
+        needles: &[S]
+    ) -> Option<(usize, usize, usize)>
+        where B: ToString, S: ToString
+    {
+        let binding = haystack.to_string();
+        let haystack = binding.as_bytes();
+        let needles: Vec<String> = needles.iter().map(|needle| needle.to_string()).collect();
+
+        let mut current_base_pos = 0usize;
+        while current_base_pos < haystack.len() {
+            for (index, needle) in needles.iter().enumerate() {
+                if !needle.is_empty() && haystack[current_base_pos..].starts_with(needle.as_bytes()) {
+                    return Some((current_base_pos, current_base_pos + needle.len(), index));
+                }
+            }
+            current_base_pos += 1;
+        }
+        None
+    }
+
+    /// # Description
+    ///
+    /// Splits the base string on every occurrence of a delimiter and returns the fields as owned strings.
+    /// Empty fields are kept, and a base string without the delimiter gives a single field containing the whole string.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being split.
+    /// * `delim` - The delimiter separating fields. Must not be empty.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<String>` - The fields of the base string in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::split;
+    ///
+    /// // result = ["a", "b", "c"]
+    /// let result = split(&"a,b,c", &",");
+    /// ```
+    pub fn split<B, S>(
+        base: &B,
+        delim: &S
+    ) -> Vec<String>
+        where B: ToString, S: ToString
+    {
+        let base = base.to_string();
+        let delim = delim.to_string();
+        assert!(!delim.is_empty());
+        base.split(delim.as_str()).map(|field| field.to_string()).collect()
+    }
+
+    /// # Description
+    ///
+    /// Finds the last occurrence of a byte in the base string, scanning backwards from the end 64 bytes at a time (a SIMD `memrchr`).
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `byte` - The byte being looked for.
+    ///
+    /// # Output
+    ///
+    /// * `Option<usize>` - The position of the last occurrence of `byte`. Returns `None` if the byte was not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::last_index_of_byte_simd;
+    ///
+    /// // result = Some(9)
+    /// let result = last_index_of_byte_simd(&"/usr/bin/env", b'/');
+    /// ```
+    pub fn last_index_of_byte_simd<B>(base: &B, byte: u8) -> Option<usize>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let haystack = binding.as_bytes();
+        let needle = u8x64::splat(byte);
+        let mut end = haystack.len();
+        while end > 0 {
+            let start = end.saturating_sub(64);
+            let valid = match end - start {
+                64 => u64::MAX,
+                remaining => (1u64 << remaining) - 1
+            };
+            let found = simd_u8x64_from_offset(haystack, start).simd_eq(needle).to_bitmask() & valid;
+            if found != 0 {
+                return Some(start + 63 - found.leading_zeros() as usize);
+            }
+            end = start;
+        }
+        None
+    }
+
+    /// # Description
+    ///
+    /// Decodes the char whose encoding starts at a byte position, such as the start of a range returned by `find_all_exact`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being read.
+    /// * `byte_index` - The byte position of the char.
+    ///
+    /// # Output
+    ///
+    /// * `Option<char>` - The char starting at `byte_index`. Returns `None` if the position is out of range or not on a char boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::char_at;
+    ///
+    /// // result = Some('é')
+    /// let result = char_at(&"héllo", 1);
+    /// ```
+    pub fn char_at<B>(base: &B, byte_index: usize) -> Option<char>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        match binding.is_char_boundary(byte_index) {
+            true => binding[byte_index..].chars().next(),
+            false => None
+        }
+    }
+
+    /// # Description
+    ///
+    /// Merges overlapping or touching ranges, such as the output of `find_all_exact`, into disjoint spans.
+    /// The ranges do not need to be sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `ranges` - The `(start, end)` ranges being merged.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<(usize, usize)>` - The merged ranges sorted by start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::merge_ranges;
+    ///
+    /// // result = [(0, 8), (10, 12)]
+    /// let result = merge_ranges(&[(4, 8), (0, 4), (10, 12)]);
+    /// ```
+    pub fn merge_ranges(ranges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let mut sorted = ranges.to_vec();
+        sorted.sort();
+        let mut merged: Vec<(usize, usize)> = Vec::with_capacity(sorted.len());
+        for (start, end) in sorted {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end))
+            }
+        }
+        merged
+    }
+
+    /// # Description
+    ///
+    /// Pairs every char in the base string with the byte position its encoding starts at.
+    /// This is the bridge between the byte positions returned by the other compare functions and char level reasoning.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being read.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<(usize, char)>` - Each char in order with its starting byte position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::char_indices;
+    ///
+    /// // result = [(0, 'h'), (1, 'é'), (3, 'y')]
+    /// let result = char_indices(&"héy");
+    /// ```
+    pub fn char_indices<B>(base: &B) -> Vec<(usize, char)>
+        where B: ToString
+    {
+        base.to_string().char_indices().collect()
+    }
+
+    /// # Description
+    ///
+    /// Calculates the Jaccard similarity of the sets of `shingle` char long substrings in two strings.
+    /// With a `shingle` of 1 this is the overlap of the two strings' character sets.
+    /// Two strings that are both too short to contain any shingle are considered identical.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first string being compared.
+    /// * `b` - The second string being compared.
+    /// * `shingle` - The length in chars of each substring. Must be greater than 0.
+    ///
+    /// # Output
+    ///
+    /// * `f64` - The size of the shingle set intersection divided by the size of the union, from 0.0 (disjoint) to 1.0 (identical).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::jaccard;
+    ///
+    /// // result = 0.333..., the shingles are {"ab", "bc"} and {"ab", "bd"} sharing only "ab"
+    /// let result = jaccard(&"abc", &"abd", 2);
+    /// ```
+    pub fn jaccard<A, B>(
+        a: &A,
+        b: &B,
+        shingle: usize
+    ) -> f64
+        where A: ToString, B: ToString
+    {
+        assert!(shingle > 0);
+        let a = a.to_string();
+        let b = b.to_string();
+        let a_shingles = shingle_set(a.as_ref(), shingle);
+        let b_shingles = shingle_set(b.as_ref(), shingle);
+        let union = a_shingles.union(&b_shingles).count();
+        if union == 0 {
+            return 1.0;
+        }
+        a_shingles.intersection(&b_shingles).count() as f64 / union as f64
+    }
+
+    fn shingle_set(base: &str, shingle: usize) -> BTreeSet<&str> {
+        ngram_ranges(base, shingle).into_iter()
+            .map(|(start, end)| &base[start..end])
+            .collect()
+    }
+
+    fn ngram_ranges(base: &str, n: usize) -> Vec<(usize, usize)> {
+        let mut boundaries: Vec<usize> = base.char_indices().map(|(pos, _)| pos).collect();
+        boundaries.push(base.len());
+        boundaries.windows(n + 1)
+            .map(|window| (window[0], window[n]))
+            .collect()
+    }
+
+    /// # Description
+    ///
+    /// Scores how similar two strings are as `1.0 - distance / max_len`, where `distance` is the byte Hamming distance.
+    /// Bytes are compared position by position and the shorter string is conceptually padded, so every byte past its end counts as one mismatch.
+    /// `max_len` is the byte length of the longer string. Two empty strings are identical.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first string being compared.
+    /// * `b` - The second string being compared.
+    ///
+    /// # Output
+    ///
+    /// * `f64` - The similarity score, from 0.0 (no positions match) to 1.0 (identical).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::similarity_ratio;
+    ///
+    /// // result = 0.75, "abcd" and "abxd" differ at one of four positions
+    /// let result = similarity_ratio(&"abcd", &"abxd");
+    /// ```
+    pub fn similarity_ratio<A, B>(a: &A, b: &B) -> f64
+        where A: ToString, B: ToString
+    {
+        let a = a.to_string();
+        let b = b.to_string();
+        let max_len = a.len().max(b.len());
+        if max_len == 0 {
+            return 1.0;
+        }
+        let matching = a.bytes().zip(b.bytes()).filter(|(a, b)| a == b).count();
+        let distance = max_len - matching;
+        1.0 - distance as f64 / max_len as f64
+    }
+
+    /// # Description
+    ///
+    /// Gets the count of a group of characters in a base string, ignoring ASCII case.
+    /// Counts are keyed by the lowercase form of each requested char. Non-ASCII case is not folded, so `'é'` and `'É'` are counted separately.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `chars` - The characters being searched and tallied.
+    ///
+    /// # Output
+    ///
+    /// * HashMap<char, usize> - A hashmap containing the lowercased characters and the number of times they appear in the base string in either case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::count_chars_ignore_case;
+    ///
+    /// // The result will look like this: {'a': 4}
+    /// let result = count_chars_ignore_case(&"aAaA", &['a']);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn count_chars_ignore_case<B>(
+        base: &B,
+        chars: &[char]
+    ) -> HashMap<char, usize>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let mut char_count: HashMap<char, usize> = HashMap::new();
+        for c_char in chars {
+            char_count.insert(c_char.to_ascii_lowercase(), 0);
+        }
+        for c_char in binding.chars() {
+            if let Some(count) = char_count.get_mut(&c_char.to_ascii_lowercase()) {
+                *count += 1;
+            }
+        }
+        char_count
+    }
+
+    /// # Description
+    ///
+    /// Converts a byte range, such as one returned by `find_all_exact`, into a char range over the same base string.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string the range refers to.
+    /// * `byte_range` - The start and end byte positions.
+    ///
+    /// # Output
+    ///
+    /// * `(usize, usize)` - The start and end char positions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either end of the range is out of bounds or not on a char boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::to_char_range;
+    ///
+    /// // "llo" is bytes 3..6 but chars 2..5 because 'é' is two bytes long
+    /// // result = (2, 5)
+    /// let result = to_char_range(&"héllo", (3, 6));
+    /// ```
+    pub fn to_char_range<B>(base: &B, byte_range: (usize, usize)) -> (usize, usize)
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let (start, end) = byte_range;
+        assert!(start <= end, "byte range start {} is after its end {}", start, end);
+        assert!(binding.is_char_boundary(start) && binding.is_char_boundary(end), "byte range ({}, {}) is not on char boundaries", start, end);
+        let char_start = binding[..start].chars().count();
+        (char_start, char_start + binding[start..end].chars().count())
+    }
+
+    /// # Description
+    ///
+    /// Encodes a name with the classic American Soundex algorithm: the first letter followed by three digits, so names that sound alike share a code.
+    /// Only ASCII letters are considered and the encoding is designed for English names; other chars are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The name being encoded.
+    ///
+    /// # Output
+    ///
+    /// * `String` - The four char Soundex code. Empty if the base string contains no ASCII letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::soundex;
+    ///
+    /// // result = "R163"
+    /// let result = soundex(&"Robert");
+    /// ```
+    pub fn soundex<B>(base: &B) -> String
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let mut letters = binding.chars()
+            .filter(|c| c.is_ascii_alphabetic())
+            .map(|c| c.to_ascii_uppercase());
+        let first = match letters.next() {
+            Some(first) => first,
+            None => return String::new()
+        };
+
+        let mut code = String::with_capacity(4);
+        code.push(first);
+        let mut last_digit = soundex_digit(first);
+        for letter in letters {
+            if code.len() == 4 {
+                break;
+            }
+            if letter == 'H' || letter == 'W' {
+                continue;
+            }
+            let digit = soundex_digit(letter);
+            if digit.is_some() && digit != last_digit {
+                code.extend(digit);
+            }
+            last_digit = digit;
+        }
+        while code.len() < 4 {
+            code.push('0');
+        }
+        code
+    }
+
+    fn soundex_digit(letter: char) -> Option<char> {
+        match letter {
+            'B' | 'F' | 'P' | 'V' => Some('1'),
+            'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+            'D' | 'T' => Some('3'),
+            'L' => Some('4'),
+            'M' | 'N' => Some('5'),
+            'R' => Some('6'),
+            _ => None
+        }
+    }
+
+    /// # Description
+    /// The result of `find_all_detailed`: every match range together with the match count and the total number of matched bytes.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub struct MatchSet {
+        ranges: Vec<(usize, usize)>,
+        total_bytes: usize
+    }
+
+    impl MatchSet {
+        pub fn count(&self) -> usize {
+            self.ranges.len()
+        }
+
+        pub fn total_bytes(&self) -> usize {
+            self.total_bytes
+        }
+
+        pub fn ranges(&self) -> &[(usize, usize)] {
+            &self.ranges
+        }
+    }
+
+    /// # Description
+    ///
+    /// Finds all sub-string occurrences like `find_all_exact`, bundling the ranges with their count and total matched bytes.
+    /// Overlapping occurrences are included and each adds its full length to the total.
+    ///
+    /// # Arguments
+    /// * `base` - The base string we are searching.
+    /// * `find` - The sub-string we are trying to find all occurrences of.
+    ///
+    /// # Output
+    /// * `MatchSet` - The ranges of every occurrence, their count and the sum of their lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::find_all_detailed;
+    ///
+    /// let result = find_all_detailed(&"test a test", &"test");
+    ///
+    /// // count = 2, total_bytes = 8, ranges = [(0, 4), (7, 11)]
+    /// let count = result.count();
+    /// ```
+    pub fn find_all_detailed<B, S>(
+        base: &B,
+        find: &S
+    ) -> MatchSet
+        where B: ToString, S: ToString
+    {
+        let mut match_set = MatchSet::default();
+        for_each_match(base, find, |start, end| {
+            match_set.ranges.push((start, end));
+            match_set.total_bytes += end - start;
+            ControlFlow::Continue(())
+        });
+        match_set
+    }
+
+    /// # Description
+    ///
+    /// Finds all substrings of at most `max_len` chars containing a set of specified characters, like `substring_char_group_count`.
+    /// Bounding the substring length cuts the work from every possible substring down to `max_len` substrings per start position,
+    /// which makes this usable on long inputs. Substrings always start and end on char boundaries.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `char_group` - the specified chars being looked for in substrings.
+    /// * `max_len` - The longest substring, in chars, that is considered.
+    ///
+    /// # Outputs
+    ///
+    /// * HashMap<String, usize> - HashMap of all strings as keys and the number of times the substring occurs as the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::substring_char_group_count_bounded;
+    ///
+    /// let str1 = String::from("abcc");
+    /// let chars = vec!['a', 'b', 'c'];
+    ///
+    /// // The result will look like this: {'abc': 1}
+    /// let result = substring_char_group_count_bounded(&str1, &chars, 3);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn substring_char_group_count_bounded<B>(
+        base: &B,
+        char_group: &[char],
+        max_len: usize
+    ) -> HashMap<String, usize>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let group: HashSet<char> = char_group.iter().copied().collect();
+        let mut boundaries: Vec<usize> = binding.char_indices().map(|(pos, _)| pos).collect();
+        boundaries.push(binding.len());
+        let mut sub_string_count: HashMap<String, usize> = HashMap::new();
+
+        for start in 0..boundaries.len() - 1 {
+            let mut found: HashSet<char> = HashSet::new();
+            let last = (start + max_len).min(boundaries.len() - 1);
+            for end in start + 1..=last {
+                let c = binding[boundaries[end - 1]..].chars().next().unwrap();
+                if group.contains(&c) {
+                    found.insert(c);
+                }
+                if found.len() == group.len() {
+                    *sub_string_count.entry(binding[boundaries[start]..boundaries[end]].to_string()).or_insert(0) += 1;
+                }
+            }
+        }
+        sub_string_count
+    }
+
+    /// # Description
+    /// A single step of the edit script returned by `edit_ops`, applied to the first string from left to right.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum EditOp {
+        /// The next char is kept as is.
+        Match,
+        /// The next char (first) is replaced by another char (second).
+        Substitute(char, char),
+        /// A char is inserted before the next char.
+        Insert(char),
+        /// The next char is removed.
+        Delete(char)
+    }
+
+    /// # Description
+    ///
+    /// Finds a shortest edit script that turns `a` into `b`, the operations behind the Levenshtein distance.
+    /// The full DP matrix is built and then backtracked from the end to reconstruct the operations. Strings are compared by char.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The string being edited.
+    /// * `b` - The string `a` is turned into.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<EditOp>` - The operations in order. The number of operations other than `EditOp::Match` is the edit distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::{edit_ops, EditOp};
+    ///
+    /// // result = [Substitute('c', 'b'), Match, Match]
+    /// let result = edit_ops(&"cat", &"bat");
+    /// ```
+    pub fn edit_ops<A, B>(a: &A, b: &B) -> Vec<EditOp>
+        where A: ToString, B: ToString
+    {
+        let a: Vec<char> = a.to_string().chars().collect();
+        let b: Vec<char> = b.to_string().chars().collect();
+        let width = b.len() + 1;
+        let mut distances = vec![0usize; (a.len() + 1) * width];
+        for i in 0..=a.len() {
+            distances[i * width] = i;
+        }
+        for (j, distance) in distances[..width].iter_mut().enumerate() {
+            *distance = j;
+        }
+        for i in 1..=a.len() {
+            for j in 1..=b.len() {
+                let substitute = distances[(i - 1) * width + j - 1] + usize::from(a[i - 1] != b[j - 1]);
+                let delete = distances[(i - 1) * width + j] + 1;
+                let insert = distances[i * width + j - 1] + 1;
+                distances[i * width + j] = substitute.min(delete).min(insert);
+            }
+        }
+
+        let mut ops: Vec<EditOp> = vec![];
+        let (mut i, mut j) = (a.len(), b.len());
+        while i > 0 || j > 0 {
+            let current = distances[i * width + j];
+            if i > 0 && j > 0 && current == distances[(i - 1) * width + j - 1] + usize::from(a[i - 1] != b[j - 1]) {
+                ops.push(match a[i - 1] == b[j - 1] {
+                    true => EditOp::Match,
+                    false => EditOp::Substitute(a[i - 1], b[j - 1])
+                });
+                i -= 1;
+                j -= 1;
+            } else if i > 0 && current == distances[(i - 1) * width + j] + 1 {
+                ops.push(EditOp::Delete(a[i - 1]));
+                i -= 1;
+            } else {
+                ops.push(EditOp::Insert(b[j - 1]));
+                j -= 1;
+            }
+        }
+        ops.reverse();
+        ops
+    }
+
+    /// # Description
+    ///
+    /// Counts the extended grapheme clusters in a string, which is usually what is meant by the "length" of displayed text.
+    /// Unlike `chars().count()`, an emoji with modifiers, a flag or a letter with combining accents counts as one.
+    /// Only available with the `unicode` feature, which adds a dependency on `unicode-segmentation`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being counted.
+    ///
+    /// # Output
+    ///
+    /// * `usize` - The number of grapheme clusters in the base string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::grapheme_count;
+    ///
+    /// // result = 2, "e\u{301}" is 'e' followed by a combining acute accent
+    /// let result = grapheme_count(&"e\u{301}!");
+    /// ```
+    #[cfg(feature = "unicode")]
+    pub fn grapheme_count<B>(base: &B) -> usize
+        where B: ToString
+    {
+        base.to_string().graphemes(true).count()
+    }
+
+    /// # Description
+    ///
+    /// Splits the base string on any of several delimiter chars and returns the byte range of each field, e.g. for a simple lexer.
+    /// Runs of consecutive delimiters are collapsed, so no empty fields are returned. Use `split_on_any_with` to keep them.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being split.
+    /// * `delimiters` - The chars that separate fields.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<(usize, usize)>` - The start and end byte positions of each non-empty field in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::split_on_any;
+    ///
+    /// // result = [(0, 1), (3, 4), (5, 6)]
+    /// let result = split_on_any(&"a, b;c", &[',', ';', ' ']);
+    /// ```
+    pub fn split_on_any<B>(base: &B, delimiters: &[char]) -> Vec<(usize, usize)>
+        where B: ToString
+    {
+        split_on_any_with(base, delimiters, true)
+    }
+
+    /// # Description
+    ///
+    /// Splits the base string on any of several delimiter chars and returns the byte range of each field.
+    /// With `collapse` set, runs of consecutive delimiters count as a single one and empty fields are dropped.
+    /// Without it, every delimiter ends a field, so empty fields (including leading and trailing ones) are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being split.
+    /// * `delimiters` - The chars that separate fields.
+    /// * `collapse` - Whether consecutive delimiters are collapsed.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<(usize, usize)>` - The start and end byte positions of each field in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::split_on_any_with;
+    ///
+    /// // result = [(0, 1), (2, 2), (3, 4)]
+    /// let result = split_on_any_with(&"a,;b", &[',', ';'], false);
+    /// ```
+    pub fn split_on_any_with<B>(base: &B, delimiters: &[char], collapse: bool) -> Vec<(usize, usize)>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let mut fields: Vec<(usize, usize)> = Vec::new();
+        let mut start = 0;
+
+        for (pos, c) in binding.char_indices() {
+            if delimiters.contains(&c) {
+                if !collapse || pos > start {
+                    fields.push((start, pos));
+                }
+                start = pos + c.len_utf8();
+            }
+        }
+        if !collapse || binding.len() > start {
+            fields.push((start, binding.len()));
+        }
+        fields
+    }
+
+    /// # Description
+    ///
+    /// Finds the start position of every occurrence of a sub-string, including overlapping ones, 64 bytes at a time.
+    /// Each chunk is compared against the first byte of `find` and only the set lanes of the resulting mask are verified,
+    /// which is much faster than a per-byte walk when matches are sparse. Matches that cross a chunk boundary are verified against the full string.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `find` - The sub-string being looked for. Must not be empty or longer than `base`.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<usize>` - The start positions of all occurrences in ascending order, the same starts `find_all_exact` returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::find_all_starts_simd;
+    ///
+    /// // result = [11, 24, 29]
+    /// let result = find_all_starts_simd(&"This is my test string! test test!", &"test");
+    /// ```
+    pub fn find_all_starts_simd<B, S>(base: &B, find: &S) -> Vec<usize>
+        where B: ToString, S: ToString
+    {
+        let binding = base.to_string();
+        let haystack = binding.as_bytes();
+        let binding = find.to_string();
+        let needle = binding.as_bytes();
+
+        assert!(haystack.len() >= needle.len() && !needle.is_empty());
+
+        let last_start = haystack.len() - needle.len();
+        let first_byte = u8x64::splat(needle[0]);
+        let mut starts: Vec<usize> = Vec::new();
+        let mut offset = 0usize;
+        while offset <= last_start {
+            let valid = match last_start + 1 - offset {
+                remaining if remaining < 64 => (1u64 << remaining) - 1,
+                _ => u64::MAX
+            };
+            let mut candidates = simd_u8x64_from_offset(haystack, offset).simd_eq(first_byte).to_bitmask() & valid;
+            while candidates != 0 {
+                let start = offset + candidates.trailing_zeros() as usize;
+                if &haystack[start..start + needle.len()] == needle {
+                    starts.push(start);
+                }
+                candidates &= candidates - 1;
+            }
+            offset += 64;
+        }
+        starts
+    }
+
+    /// # Description
+    ///
+    /// Checks if the base string contains a single char, without going through the sub-string search.
+    /// An ASCII char is looked for with a byte scan, 64 bytes at a time when the `simd` feature is enabled, and stops at the first hit.
+    /// A multibyte char falls back to comparing the chars of the base string.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `c` - The char being looked for.
+    ///
+    /// # Output
+    ///
+    /// * `bool` - True if the char occurs in the base string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::contains_char;
+    ///
+    /// // result = true
+    /// let result = contains_char(&"café", 'é');
+    /// ```
+    pub fn contains_char<B>(base: &B, c: char) -> bool
+        where B: ToString
+    {
+        let binding = base.to_string();
+        if c.is_ascii() {
+            #[cfg(feature = "simd")]
+            return next_byte_position_simd(binding.as_bytes(), 0, c as u8).is_some();
+            #[cfg(not(feature = "simd"))]
+            return binding.as_bytes().contains(&(c as u8));
+        }
+        binding.chars().any(|current| current == c)
+    }
+
+    /// # Description
+    ///
+    /// Checks if the chars of `needle` appear in `haystack` in the same order, though not necessarily next to each other.
+    /// This is the loose "does this pattern match" check behind fuzzy finders. Strings are compared by char.
+    ///
+    /// # Arguments
+    ///
+    /// * `needle` - The chars being looked for, in order.
+    /// * `haystack` - The string being searched.
+    ///
+    /// # Output
+    ///
+    /// * `bool` - True if `needle` is a subsequence of `haystack`. An empty needle is a subsequence of every string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::is_subsequence;
+    ///
+    /// // result = true
+    /// let result = is_subsequence(&"ace", &"abcde");
+    /// ```
+    pub fn is_subsequence<A, B>(needle: &A, haystack: &B) -> bool
+        where A: ToString, B: ToString
+    {
+        let needle = needle.to_string();
+        let haystack = haystack.to_string();
+        let mut haystack_chars = haystack.chars();
+        needle.chars().all(|n| haystack_chars.any(|h| h == n))
+    }
+
+    /// # Description
+    ///
+    /// Scores how well `pattern` fuzzy matches `candidate`, so a list of candidates can be ranked like in a command palette.
+    /// The pattern chars are matched greedily from left to right, comparing by char, and the score is built up as follows:
+    ///
+    /// * every matched char scores 16 points,
+    /// * a match at a word start scores 10 more. A word starts at the beginning of the candidate, after a char that is not alphanumeric or at an uppercase char following a lowercase one,
+    /// * a match directly after the previous match scores 8 more,
+    /// * every skipped char between two matches costs 1 point, chars before the first match and after the last one are free.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern typed by the user.
+    /// * `candidate` - The string being scored.
+    ///
+    /// # Output
+    ///
+    /// * `Option<i32>` - The score, higher is better. Returns `None` if `pattern` is not a subsequence of `candidate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::fuzzy_score;
+    ///
+    /// // result = Some(49), both matches are at word starts with 3 chars skipped in between
+    /// let result = fuzzy_score(&"fb", &"foo_bar");
+    /// ```
+    pub fn fuzzy_score<A, B>(pattern: &A, candidate: &B) -> Option<i32>
+        where A: ToString, B: ToString
+    {
+        let pattern = pattern.to_string();
+        let candidate = candidate.to_string();
+        let mut pattern_chars = pattern.chars().peekable();
+        let mut score = 0i32;
+        let mut previous: Option<char> = None;
+        let mut last_match: Option<usize> = None;
+
+        for (index, c) in candidate.chars().enumerate() {
+            let Some(&wanted) = pattern_chars.peek() else {
+                break;
+            };
+            if c == wanted {
+                score += 16;
+                let word_start = match previous {
+                    None => true,
+                    Some(p) => !p.is_alphanumeric() || (p.is_lowercase() && c.is_uppercase())
+                };
+                if word_start {
+                    score += 10;
+                }
+                match last_match {
+                    Some(last) if last + 1 == index => score += 8,
+                    Some(last) => score -= (index - last - 1) as i32,
+                    None => {}
+                }
+                last_match = Some(index);
+                pattern_chars.next();
+            }
+            previous = Some(c);
+        }
+        match pattern_chars.peek() {
+            Some(_) => None,
+            None => Some(score)
+        }
+    }
+
+    /// # Description
+    ///
+    /// Finds the first occurrence of any char from a set, like `str::find` with a char set pattern.
+    /// When the `simd` feature is enabled and every char in the set is ASCII, the base string is compared against all of them 64 bytes at a time.
+    /// Otherwise the chars of the base string are checked one by one.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `chars` - The set of chars being looked for.
+    ///
+    /// # Output
+    ///
+    /// * `Option<usize>` - The byte position of the first char from the set. Returns `None` if none of them occur.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::first_index_of_any;
+    ///
+    /// // result = Some(1)
+    /// let result = first_index_of_any(&"a;b,c", &[',', ';']);
+    /// ```
+    pub fn first_index_of_any<B>(base: &B, chars: &[char]) -> Option<usize>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        #[cfg(feature = "simd")]
+        if !chars.is_empty() && chars.iter().all(char::is_ascii) {
+            let haystack = binding.as_bytes();
+            let needles: Vec<u8x64> = chars.iter().map(|&c| u8x64::splat(c as u8)).collect();
+            let mut offset = 0usize;
+            while offset < haystack.len() {
+                let valid = match haystack.len() - offset {
+                    remaining if remaining < 64 => (1u64 << remaining) - 1,
+                    _ => u64::MAX
+                };
+                let chunk = simd_u8x64_from_offset(haystack, offset);
+                let found = needles.iter()
+                    .fold(0u64, |found, needle| found | chunk.simd_eq(*needle).to_bitmask()) & valid;
+                if found != 0 {
+                    return Some(offset + found.trailing_zeros() as usize);
+                }
+                offset += 64;
+            }
+            return None;
+        }
+        binding.char_indices().find(|(_, c)| chars.contains(c)).map(|(pos, _)| pos)
+    }
+
+    /// # Description
+    ///
+    /// Finds every window of `n` consecutive chars in the base string, the shingles used by `jaccard`.
+    /// Windows are counted in chars rather than bytes, so multibyte chars are never split.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string the windows are taken from.
+    /// * `n` - The number of chars in each window. Must be greater than 0.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<(usize, usize)>` - The start and end byte positions of every window in order. Empty if the base string has fewer than `n` chars.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::char_ngrams;
+    ///
+    /// // result = [(0, 2), (1, 3), (2, 4)], the ranges of "ab", "bc" and "cd"
+    /// let result = char_ngrams(&"abcd", 2);
+    /// ```
+    pub fn char_ngrams<B>(base: &B, n: usize) -> Vec<(usize, usize)>
+        where B: ToString
+    {
+        assert!(n > 0);
+        ngram_ranges(base.to_string().as_str(), n)
+    }
+
+    /// # Description
+    ///
+    /// Counts the distinct chars (Unicode scalar values) in the base string.
+    /// ASCII chars are tracked in an `AsciiCharSet` bitset, so only non-ASCII chars need a set lookup.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being counted.
+    ///
+    /// # Output
+    ///
+    /// * `usize` - The number of different chars in the base string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::distinct_char_count;
+    ///
+    /// // result = 3
+    /// let result = distinct_char_count(&"aabbc");
+    /// ```
+    pub fn distinct_char_count<B>(base: &B) -> usize
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let mut ascii = AsciiCharSet::new();
+        let mut other: BTreeSet<char> = BTreeSet::new();
+        for c in binding.chars() {
+            match c.is_ascii() {
+                true => ascii.insert(c as u8),
+                false => {
+                    other.insert(c);
+                }
+            }
+        }
+        ascii.bits.iter().map(|bits| bits.count_ones() as usize).sum::<usize>() + other.len()
+    }
+
+    /// # Description
+    ///
+    /// Checks the base string against a list of prefixes, e.g. to dispatch on a URL scheme or command name.
+    /// The prefixes are tried in order and the search stops at the first one that matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being checked.
+    /// * `prefixes` - The prefixes being tried, in order.
+    ///
+    /// # Output
+    ///
+    /// * `Option<usize>` - The index of the first prefix the base string starts with. Returns `None` if none of them match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::starts_with_any;
+    ///
+    /// // result = Some(1)
+    /// let result = starts_with_any(&"https://x", &["http://", "https://"]);
+    /// ```
+    pub fn starts_with_any<B, S>(base: &B, prefixes: &[S]) -> Option<usize>
+        where B: ToString, S: ToString
+    {
+        let base = base.to_string();
+        prefixes.iter().position(|prefix| base.starts_with(prefix.to_string().as_str()))
+    }
+
+    /// # Description
+    ///
+    /// Checks the base string against a list of suffixes, e.g. to dispatch on a file extension.
+    /// The suffixes are tried in order and the search stops at the first one that matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being checked.
+    /// * `suffixes` - The suffixes being tried, in order.
+    ///
+    /// # Output
+    ///
+    /// * `Option<usize>` - The index of the first suffix the base string ends with. Returns `None` if none of them match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::ends_with_any;
+    ///
+    /// // result = Some(0)
+    /// let result = ends_with_any(&"archive.tar.gz", &[".gz", ".zip"]);
+    /// ```
+    pub fn ends_with_any<B, S>(base: &B, suffixes: &[S]) -> Option<usize>
+        where B: ToString, S: ToString
+    {
+        let base = base.to_string();
+        suffixes.iter().position(|suffix| base.ends_with(suffix.to_string().as_str()))
+    }
+
+    /// # Description
+    ///
+    /// Marks every position of a char in the base string in a bitmap with one bit per byte, so positions of different chars
+    /// can be combined with cheap word-wise set operations instead of merging lists of positions.
+    /// Bit `i % 64` of word `i / 64` is set when the char starts at byte `i`. With the `simd` feature an ASCII char fills a whole word
+    /// from a single 64 byte comparison.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `c` - The char being looked for.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<u64>` - The bitmap, one word for every 64 bytes of the base string. Use `bitmap_count` and `bitmap_iter` to read it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::char_positions_bitmap;
+    ///
+    /// // result = [0b10010], 'a' is at bytes 1 and 4
+    /// let result = char_positions_bitmap(&"banan", 'a');
+    /// ```
+    pub fn char_positions_bitmap<B>(base: &B, c: char) -> Vec<u64>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let haystack = binding.as_bytes();
+        let mut bitmap = vec![0u64; haystack.len().div_ceil(64)];
+        #[cfg(feature = "simd")]
+        if c.is_ascii() {
+            let needle = u8x64::splat(c as u8);
+            for (index, word) in bitmap.iter_mut().enumerate() {
+                let offset = index * 64;
+                let valid = match haystack.len() - offset {
+                    remaining if remaining < 64 => (1u64 << remaining) - 1,
+                    _ => u64::MAX
+                };
+                *word = simd_u8x64_from_offset(haystack, offset).simd_eq(needle).to_bitmask() & valid;
+            }
+            return bitmap;
+        }
+        for (pos, _) in binding.match_indices(c) {
+            bitmap[pos / 64] |= 1u64 << (pos % 64);
+        }
+        bitmap
+    }
+
+    /// # Description
+    ///
+    /// Counts the set bits of a bitmap returned by `char_positions_bitmap`, i.e. the number of positions it marks.
+    ///
+    /// # Arguments
+    ///
+    /// * `bitmap` - The bitmap being counted.
+    ///
+    /// # Output
+    ///
+    /// * `usize` - The number of set bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::{bitmap_count, char_positions_bitmap};
+    ///
+    /// // result = 2
+    /// let result = bitmap_count(&char_positions_bitmap(&"banan", 'a'));
+    /// ```
+    pub fn bitmap_count(bitmap: &[u64]) -> usize {
+        bitmap.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// # Description
+    ///
+    /// Iterates over the positions marked in a bitmap returned by `char_positions_bitmap`, in ascending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `bitmap` - The bitmap being read.
+    ///
+    /// # Output
+    ///
+    /// * `impl Iterator<Item = usize>` - The byte position of every set bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::{bitmap_iter, char_positions_bitmap};
+    ///
+    /// // result = [1, 4]
+    /// let result: Vec<usize> = bitmap_iter(&char_positions_bitmap(&"banan", 'a')).collect();
+    /// ```
+    pub fn bitmap_iter(bitmap: &[u64]) -> impl Iterator<Item = usize> + '_ {
+        bitmap.iter().enumerate().flat_map(|(index, &word)| {
+            let mut remaining = word;
+            core::iter::from_fn(move || {
+                if remaining == 0 {
+                    return None;
+                }
+                let bit = remaining.trailing_zeros() as usize;
+                remaining &= remaining - 1;
+                Some(index * 64 + bit)
+            })
+        })
+    }
+
+    /// # Description
+    ///
+    /// Measures how much of the haystack is covered by non-overlapping matches of the needle, useful for spotting repetitive or boilerplate content.
+    /// The matches are counted with `substring_count_scalar`.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The base string being measured.
+    /// * `needle` - The sub-string being counted. Must not be empty.
+    ///
+    /// # Output
+    ///
+    /// * `f64` - The matched bytes divided by the haystack bytes, from 0.0 to 1.0. A haystack shorter than the needle gives 0.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::match_density;
+    ///
+    /// // result = 0.5
+    /// let result = match_density(&"abab----", &"ab");
+    /// ```
+    pub fn match_density<B, S>(haystack: &B, needle: &S) -> f64
+        where B: ToString, S: ToString
+    {
+        let haystack = haystack.to_string();
+        let needle = needle.to_string();
+        assert!(!needle.is_empty());
+        if haystack.len() < needle.len() {
+            return 0.0;
+        }
+        let matched = substring_count_scalar(&haystack, &needle) * needle.len();
+        matched as f64 / haystack.len() as f64
+    }
+
+    /// # Description
+    ///
+    /// Finds the longest substring that reads the same forwards and backwards, comparing chars like `is_palindrome`.
+    /// Uses Manacher's algorithm for linear time: a separator slot is placed around every char so odd and even length palindromes
+    /// both have a center, and the radius found for a mirrored center is reused as the starting radius while inside a known palindrome.
+    /// When several palindromes share the longest length the leftmost one is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    ///
+    /// # Output
+    ///
+    /// * `Option<(usize, usize)>` - The start and end byte positions of the longest palindrome. Returns `None` for an empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::longest_palindrome;
+    ///
+    /// // result = Some((0, 3)), the range of "bab"
+    /// let result = longest_palindrome(&"babad");
+    /// ```
+    pub fn longest_palindrome<B>(base: &B) -> Option<(usize, usize)>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let chars: Vec<char> = binding.chars().collect();
+        if chars.is_empty() {
+            return None;
+        }
+        let mut boundaries: Vec<usize> = binding.char_indices().map(|(pos, _)| pos).collect();
+        boundaries.push(binding.len());
+
+        // Even slots are separators, odd slot `i` holds `chars[i / 2]`.
+        let slots = 2 * chars.len() + 1;
+        let mirrors_match = |left: usize, right: usize| left.is_multiple_of(2) || chars[left / 2] == chars[right / 2];
+        let mut radii = vec![0usize; slots];
+        let (mut center, mut right) = (0usize, 0usize);
+        let (mut best_center, mut best_radius) = (0usize, 0usize);
+
+        for slot in 0..slots {
+            let mut radius = match slot < right {
+                true => radii[2 * center - slot].min(right - slot),
+                false => 0
+            };
+            while slot > radius && slot + radius + 1 < slots && mirrors_match(slot - radius - 1, slot + radius + 1) {
+                radius += 1;
+            }
+            radii[slot] = radius;
+            if slot + radius > right {
+                center = slot;
+                right = slot + radius;
+            }
+            if radius > best_radius {
+                best_center = slot;
+                best_radius = radius;
+            }
+        }
+
+        let start = (best_center - best_radius) / 2;
+        Some((boundaries[start], boundaries[start + best_radius]))
+    }
+
+    /// # Description
+    ///
+    /// Counts every char in the base string and returns the counts sorted from most to least frequent, ready for a histogram or top-N list.
+    /// Chars with the same count are ordered by char value.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being counted.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<(char, usize)>` - Each distinct char with the number of times it occurs, most frequent first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::char_frequencies_sorted;
+    ///
+    /// // result = [('a', 3), ('b', 2), ('c', 1)]
+    /// let result = char_frequencies_sorted(&"aaabbc");
+    /// ```
+    pub fn char_frequencies_sorted<B>(base: &B) -> Vec<(char, usize)>
+        where B: ToString
+    {
+        let mut char_count: BTreeMap<char, usize> = BTreeMap::new();
+        for c in base.to_string().chars() {
+            *char_count.entry(c).or_insert(0) += 1;
+        }
+        let mut frequencies: Vec<(char, usize)> = char_count.into_iter().collect();
+        frequencies.sort_by_key(|&(_, count)| core::cmp::Reverse(count));
+        frequencies
+    }
+
+    /// # Description
+    ///
+    /// Checks if a byte sequence occurs in a byte slice. Works on arbitrary binary data, nothing is assumed about UTF-8 and nothing is allocated.
+    /// An empty needle never matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The bytes being searched.
+    /// * `needle` - The byte sequence being looked for.
+    ///
+    /// # Output
+    ///
+    /// * `bool` - True if `needle` occurs in `haystack`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::contains_bytes;
+    ///
+    /// // result = true
+    /// let result = contains_bytes(&[0x00, 0x89, 0x50, 0x4E, 0x47], &[0x89, 0x50, 0x4E, 0x47]);
+    /// ```
+    pub fn contains_bytes(haystack: &[u8], needle: &[u8]) -> bool {
+        find_first_bytes(haystack, needle).is_some()
+    }
+
+    /// # Description
+    ///
+    /// Finds the first occurrence of a byte sequence in a byte slice, without any UTF-8 assumption or allocation.
+    /// With the `simd` feature enabled candidate positions are found 64 bytes at a time by comparing against the first byte of `needle`.
+    /// An empty needle never matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The bytes being searched.
+    /// * `needle` - The byte sequence being looked for.
+    ///
+    /// # Output
+    ///
+    /// * `Option<usize>` - The start position of the first occurrence. Returns `None` if `needle` was not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::find_first_bytes;
+    ///
+    /// // result = Some(2)
+    /// let result = find_first_bytes(&[0xFF, 0x00, 0xFE, 0xFF], &[0xFE, 0xFF]);
+    /// ```
+    pub fn find_first_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        next_bytes_position(haystack, needle, 0)
+    }
+
+    /// # Description
+    ///
+    /// Finds every occurrence of a byte sequence in a byte slice, including overlapping ones, without any UTF-8 assumption.
+    /// An empty needle never matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The bytes being searched.
+    /// * `needle` - The byte sequence being looked for.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<(usize, usize)>` - The start and end positions of every occurrence, in the same form as `find_all_exact`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::find_all_bytes;
+    ///
+    /// // result = [(0, 2), (1, 3)]
+    /// let result = find_all_bytes(&[0xFF, 0xFF, 0xFF], &[0xFF, 0xFF]);
+    /// ```
+    pub fn find_all_bytes(haystack: &[u8], needle: &[u8]) -> Vec<(usize, usize)> {
+        let mut matches: Vec<(usize, usize)> = vec![];
+        let mut from = 0usize;
+        while let Some(start) = next_bytes_position(haystack, needle, from) {
+            matches.push((start, start + needle.len()));
+            from = start + 1;
+        }
+        matches
+    }
+
+    fn next_bytes_position(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+        let first_byte = *needle.first()?;
+        let last_start = haystack.len().checked_sub(needle.len())?;
+        let mut pos = from;
+        while pos <= last_start {
+            #[cfg(feature = "simd")]
+            let candidate = next_byte_position_simd(haystack, pos, first_byte);
+            #[cfg(not(feature = "simd"))]
+            let candidate = haystack[pos..].iter().position(|byte| *byte == first_byte).map(|offset| pos + offset);
+            match candidate {
+                Some(candidate) if candidate <= last_start => pos = candidate,
+                _ => return None
+            }
+            if &haystack[pos..pos + needle.len()] == needle {
+                return Some(pos);
+            }
+            pos += 1;
+        }
+        None
+    }
+
+    /// # Description
+    ///
+    /// Computes the Jaro-Winkler similarity of two strings, which works better than a Levenshtein ratio for short strings such as names.
+    /// The Jaro similarity counts the chars that match within a window of half the longer string and how many of them are transposed,
+    /// then the Winkler step boosts strings sharing a common prefix of up to 4 chars using the standard scaling factor of 0.1.
+    /// Strings are compared by char.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first string being compared.
+    /// * `b` - The second string being compared.
+    ///
+    /// # Output
+    ///
+    /// * `f64` - The similarity from 0.0 (nothing in common) to 1.0 (identical). Two empty strings are identical.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::jaro_winkler;
+    ///
+    /// // result ≈ 0.961
+    /// let result = jaro_winkler(&"martha", &"marhta");
+    /// ```
+    pub fn jaro_winkler<A, B>(a: &A, b: &B) -> f64
+        where A: ToString, B: ToString
+    {
+        const PREFIX_SCALE: f64 = 0.1;
+        const MAX_PREFIX: usize = 4;
+
+        let a: Vec<char> = a.to_string().chars().collect();
+        let b: Vec<char> = b.to_string().chars().collect();
+        if a.is_empty() && b.is_empty() {
+            return 1.0;
+        }
+        if a.is_empty() || b.is_empty() {
+            return 0.0;
+        }
+
+        let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+        let mut a_matched = vec![false; a.len()];
+        let mut b_matched = vec![false; b.len()];
+        let mut matches = 0usize;
+        for (i, a_char) in a.iter().enumerate() {
+            let end = (i + window + 1).min(b.len());
+            for j in i.saturating_sub(window)..end {
+                if !b_matched[j] && b[j] == *a_char {
+                    a_matched[i] = true;
+                    b_matched[j] = true;
+                    matches += 1;
+                    break;
+                }
+            }
+        }
+        if matches == 0 {
+            return 0.0;
+        }
+
+        let a_order = a.iter().zip(&a_matched).filter(|(_, matched)| **matched).map(|(c, _)| c);
+        let b_order = b.iter().zip(&b_matched).filter(|(_, matched)| **matched).map(|(c, _)| c);
+        let transpositions = a_order.zip(b_order).filter(|(a_char, b_char)| a_char != b_char).count() / 2;
+
+        let matches = matches as f64;
+        let jaro = (matches / a.len() as f64 + matches / b.len() as f64 + (matches - transpositions as f64) / matches) / 3.0;
+        let prefix = a.iter().zip(&b).take(MAX_PREFIX).take_while(|(a_char, b_char)| a_char == b_char).count();
+        jaro + prefix as f64 * PREFIX_SCALE * (1.0 - jaro)
+    }
+
+    /// # Description
+    /// A needle prepared once for searching any number of haystacks, e.g. filtering many log lines for one term.
+    /// With the `simd` feature the first and last byte of the needle are splatted up front, and each 64 byte chunk of a haystack is compared
+    /// against both so only positions where both bytes line up are verified. Without it the first byte is used to skip ahead.
+    /// Positions are byte offsets and an empty needle never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::Searcher;
+    ///
+    /// let searcher = Searcher::new(&"error");
+    ///
+    /// // matching = ["error: disk full", "fatal error"]
+    /// let matching: Vec<&str> = ["ok", "error: disk full", "fatal error"].into_iter()
+    ///     .filter(|line| searcher.contains(line))
+    ///     .collect();
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct Searcher {
+        needle: Vec<u8>,
+        #[cfg(feature = "simd")]
+        first: u8x64,
+        #[cfg(feature = "simd")]
+        last: u8x64
+    }
+
+    impl Searcher {
+        pub fn new<S>(needle: &S) -> Self
+            where S: ToString
+        {
+            let needle = needle.to_string().into_bytes();
+            Searcher {
+                #[cfg(feature = "simd")]
+                first: u8x64::splat(needle.first().copied().unwrap_or(0)),
+                #[cfg(feature = "simd")]
+                last: u8x64::splat(needle.last().copied().unwrap_or(0)),
+                needle
+            }
+        }
+
+        /// # Description
+        /// Checks if the needle occurs in the haystack, stopping at the first match.
+        pub fn contains<B>(&self, haystack: &B) -> bool
+            where B: ToString
+        {
+            let mut found = false;
+            self.for_each_start(haystack.to_string().as_bytes(), |_| {
+                found = true;
+                ControlFlow::Break(())
+            });
+            found
+        }
+
+        /// # Description
+        /// Finds the start and end positions of every occurrence of the needle, including overlapping ones, like `find_all_exact`.
+        pub fn find_all<B>(&self, haystack: &B) -> Vec<(usize, usize)>
+            where B: ToString
+        {
+            let mut matches: Vec<(usize, usize)> = vec![];
+            self.for_each_start(haystack.to_string().as_bytes(), |start| {
+                matches.push((start, start + self.needle.len()));
+                ControlFlow::Continue(())
+            });
+            matches
+        }
+
+        /// # Description
+        /// Counts the non-overlapping occurrences of the needle, the same count `substring_count_scalar` gives.
+        pub fn count<B>(&self, haystack: &B) -> usize
+            where B: ToString
+        {
+            let mut count = 0usize;
+            let mut next_start = 0usize;
+            self.for_each_start(haystack.to_string().as_bytes(), |start| {
+                if start >= next_start {
+                    count += 1;
+                    next_start = start + self.needle.len();
+                }
+                ControlFlow::Continue(())
+            });
+            count
+        }
+
+        fn for_each_start<F>(&self, haystack: &[u8], mut f: F)
+            where F: FnMut(usize) -> ControlFlow<()>
+        {
+            if self.needle.is_empty() || self.needle.len() > haystack.len() {
+                return;
+            }
+            let last_start = haystack.len() - self.needle.len();
+
+            #[cfg(feature = "simd")]
+            {
+                let last_offset = self.needle.len() - 1;
+                let mut offset = 0usize;
+                while offset <= last_start {
+                    let valid = match last_start + 1 - offset {
+                        remaining if remaining < 64 => (1u64 << remaining) - 1,
+                        _ => u64::MAX
+                    };
+                    let mut candidates = simd_u8x64_from_offset(haystack, offset).simd_eq(self.first).to_bitmask()
+                        & simd_u8x64_from_offset(haystack, offset + last_offset).simd_eq(self.last).to_bitmask()
+                        & valid;
+                    while candidates != 0 {
+                        let start = offset + candidates.trailing_zeros() as usize;
+                        if haystack[start..start + self.needle.len()] == self.needle[..] && f(start).is_break() {
+                            return;
+                        }
+                        candidates &= candidates - 1;
+                    }
+                    offset += 64;
+                }
+            }
+
+            #[cfg(not(feature = "simd"))]
+            {
+                let first_byte = self.needle[0];
+                let mut start = 0usize;
+                while let Some(offset) = haystack[start..=last_start].iter().position(|byte| *byte == first_byte) {
+                    start += offset;
+                    if haystack[start..start + self.needle.len()] == self.needle[..] && f(start).is_break() {
+                        return;
+                    }
+                    start += 1;
+                    if start > last_start {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    /// # Description
+    ///
+    /// Finds sub-string occurrences like `find_all_exact`, but stops once `max` ranges have been collected.
+    /// This bounds the allocation on pathological inputs, such as a short repeating needle in a huge haystack.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `find` - The sub-string being looked for.
+    /// * `max` - The maximum number of ranges returned. A `max` of 0 returns immediately.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<(usize, usize)>` - The start and end positions of the first `max` occurrences, overlapping ones included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::find_all_exact_capped;
+    ///
+    /// // result = [(11, 15), (24, 28)]
+    /// let result = find_all_exact_capped(&"This is my test string! test test!", &"test", 2);
+    /// ```
+    pub fn find_all_exact_capped<B, S>(
+        base: &B,
+        find: &S,
+        max: usize
+    ) -> Vec<(usize, usize)>
+        where B: ToString, S: ToString
+    {
+        let mut matches: Vec<(usize, usize)> = vec![];
+        if max == 0 {
+            return matches;
+        }
+        for_each_match(base, find, |start, end| {
+            matches.push((start, end));
+            match matches.len() < max {
+                true => ControlFlow::Continue(()),
+                false => ControlFlow::Break(())
+            }
+        });
+        matches
+    }
+
+    /// # Description
+    ///
+    /// Checks if the whole text matches a glob pattern, a lightweight alternative to a regex for filename style matching.
+    /// `*` matches any sequence of chars (including none) and `?` matches exactly one char. A backslash makes the next char literal,
+    /// so `\*` and `\?` match a literal `*` and `?` and `\\` matches a backslash. Matching is done by char with iterative backtracking
+    /// to the most recent `*`, so it never recurses.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text being matched.
+    /// * `pattern` - The glob pattern.
+    ///
+    /// # Output
+    ///
+    /// * `bool` - True if the pattern matches the entire text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::matches_glob;
+    ///
+    /// // result = true
+    /// let result = matches_glob(&"file.txt", &"*.txt");
+    /// ```
+    pub fn matches_glob<B, P>(text: &B, pattern: &P) -> bool
+        where B: ToString, P: ToString
+    {
+        let pattern = pattern.to_string();
+        let mut tokens: Vec<GlobToken> = Vec::new();
+        let mut pattern_chars = pattern.chars();
+        while let Some(c) = pattern_chars.next() {
+            tokens.push(match c {
+                '*' => GlobToken::Star,
+                '?' => GlobToken::Any,
+                '\\' => GlobToken::Literal(pattern_chars.next().unwrap_or('\\')),
+                _ => GlobToken::Literal(c)
+            });
+        }
+        let text: Vec<char> = text.to_string().chars().collect();
+
+        let (mut t, mut p) = (0usize, 0usize);
+        let mut backtrack: Option<(usize, usize)> = None;
+        while t < text.len() {
+            match tokens.get(p) {
+                Some(GlobToken::Any) => {
+                    t += 1;
+                    p += 1;
+                }
+                Some(GlobToken::Literal(c)) if *c == text[t] => {
+                    t += 1;
+                    p += 1;
+                }
+                Some(GlobToken::Star) => {
+                    backtrack = Some((p, t));
+                    p += 1;
+                }
+                _ => match backtrack {
+                    Some((star, star_text)) => {
+                        backtrack = Some((star, star_text + 1));
+                        p = star + 1;
+                        t = star_text + 1;
+                    }
+                    None => return false
+                }
+            }
+        }
+        tokens[p..].iter().all(|token| *token == GlobToken::Star)
+    }
+
+    #[derive(PartialEq)]
+    enum GlobToken {
+        Star,
+        Any,
+        Literal(char)
+    }
+
+    /// # Description
+    ///
+    /// Computes the Shannon entropy of the base string in bits per byte, from the counts of `byte_histogram`.
+    /// Plain English text usually lands around 4 to 5 bits per byte. A string is always valid UTF-8, which rules out some byte values,
+    /// so use `shannon_entropy_bytes` to measure binary data that could reach the maximum of 8. Needs the `std` feature for the logarithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being measured.
+    ///
+    /// # Output
+    ///
+    /// * `f64` - The entropy in bits per byte. An empty string has an entropy of 0.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::shannon_entropy;
+    ///
+    /// // result = 1.0, two equally likely bytes
+    /// let result = shannon_entropy(&"abab");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn shannon_entropy<B>(base: &B) -> f64
+        where B: ToString
+    {
+        histogram_entropy(&byte_histogram(base))
+    }
+
+    /// # Description
+    ///
+    /// Computes the Shannon entropy of arbitrary bytes in bits per byte, e.g. to tell compressed or encrypted blobs (close to 8.0) from plain text.
+    /// Needs the `std` feature for the logarithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes being measured.
+    ///
+    /// # Output
+    ///
+    /// * `f64` - The entropy from 0.0 to 8.0. No bytes have an entropy of 0.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::shannon_entropy_bytes;
+    ///
+    /// // result = 8.0, every byte value occurs once
+    /// let bytes: Vec<u8> = (0..=255).collect();
+    /// let result = shannon_entropy_bytes(&bytes);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn shannon_entropy_bytes(bytes: &[u8]) -> f64 {
+        histogram_entropy(&bytes_histogram(bytes))
+    }
+
+    #[cfg(feature = "std")]
+    fn histogram_entropy(histogram: &[usize; 256]) -> f64 {
+        let total: usize = histogram.iter().sum();
+        if total == 0 {
+            return 0.0;
+        }
+        histogram.iter()
+            .filter(|count| **count > 0)
+            .map(|count| {
+                let probability = *count as f64 / total as f64;
+                -probability * probability.log2()
+            })
+            .sum()
+    }
+
+    /// # Description
+    ///
+    /// Finds the distinct windows of `n` chars that occur in both strings, e.g. for near-duplicate or plagiarism detection.
+    /// The windows are the `char_ngrams` of each string, collected into the same shingle sets `jaccard` compares.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first string being compared.
+    /// * `b` - The second string being compared.
+    /// * `n` - The number of chars in each window. Must be greater than 0.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<String>` - Every shared window once, in sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::shared_ngrams;
+    ///
+    /// // result = ["cd", "de"]
+    /// let result = shared_ngrams(&"abcde", &"zcdef", 2);
+    /// ```
+    pub fn shared_ngrams<A, B>(a: &A, b: &B, n: usize) -> Vec<String>
+        where A: ToString, B: ToString
+    {
+        assert!(n > 0);
+        let a = a.to_string();
+        let b = b.to_string();
+        let a_shingles = shingle_set(a.as_ref(), n);
+        shingle_set(b.as_ref(), n).into_iter()
+            .filter(|shingle| a_shingles.contains(shingle))
+            .map(|shingle| shingle.to_string())
+            .collect()
+    }
+
+    /// # Description
+    ///
+    /// Finds every occurrence of a sub-string and returns the matched text itself rather than its range.
+    /// For an exact search every match equals `find`; see `find_all_matches_ignore_case` where the matched text keeps the casing of the base string.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `find` - The sub-string being looked for.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<String>` - The text of every occurrence found by `find_all_exact`, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::find_all_matches;
+    ///
+    /// // result = ["test", "test"]
+    /// let result = find_all_matches(&"test x test", &"test");
+    /// ```
+    pub fn find_all_matches<B, S>(base: &B, find: &S) -> Vec<String>
+        where B: ToString, S: ToString
+    {
+        let base = base.to_string();
+        find_all_exact(&base, find).into_iter()
+            .map(|(start, end)| base[start..end].to_string())
+            .collect()
+    }
+
+    /// # Description
+    ///
+    /// Finds every occurrence of a sub-string ignoring case and returns the matched text as it appears in the base string.
+    /// Chars are compared by their lowercase forms, one char of the base string for every char of `find`. Overlapping matches are included like in `find_all_exact`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `find` - The sub-string being looked for, in any case.
+    ///
+    /// # Output
+    ///
+    /// * `Vec<String>` - The text of every occurrence with the casing of the base string, in order. Empty if `find` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::find_all_matches_ignore_case;
+    ///
+    /// // result = ["Test", "TEST"]
+    /// let result = find_all_matches_ignore_case(&"Test x TEST", &"test");
+    /// ```
+    pub fn find_all_matches_ignore_case<B, S>(base: &B, find: &S) -> Vec<String>
+        where B: ToString, S: ToString
+    {
+        let base = base.to_string();
+        let find: Vec<char> = find.to_string().chars().collect();
+        let mut matches: Vec<String> = vec![];
+        if find.is_empty() {
+            return matches;
+        }
+        let chars: Vec<(usize, char)> = base.char_indices().collect();
+        for index in 0..(chars.len() + 1).saturating_sub(find.len()) {
+            let is_match = chars[index..index + find.len()].iter()
+                .zip(&find)
+                .all(|(&(_, a), b)| a == *b || a.to_lowercase().eq(b.to_lowercase()));
+            if is_match {
+                let end = chars.get(index + find.len()).map_or(base.len(), |&(pos, _)| pos);
+                matches.push(base[chars[index].0..end].to_string());
+            }
+        }
+        matches
+    }
+
+    /// # Description
+    /// A lazy iterator over the fields of a string split on a delimiter, created by `split_iter`.
+    /// Fields are borrowed from the base string, so nothing is allocated.
+    #[derive(Clone, Debug)]
+    pub struct Split<'a> {
+        remainder: Option<&'a str>,
+        delim: &'a str
+    }
+
+    impl<'a> Iterator for Split<'a> {
+        type Item = &'a str;
+
+        fn next(&mut self) -> Option<&'a str> {
+            let remainder = self.remainder?;
+            match remainder.find(self.delim) {
+                Some(pos) => {
+                    self.remainder = Some(&remainder[pos + self.delim.len()..]);
+                    Some(&remainder[..pos])
+                }
+                None => {
+                    self.remainder = None;
+                    Some(remainder)
+                }
+            }
+        }
+    }
+
+    /// # Description
+    ///
+    /// Splits the base string on every occurrence of a delimiter, yielding the fields lazily as slices of the base string.
+    /// The zero allocation counterpart of `split`: empty fields are kept, so a trailing delimiter gives a trailing empty field.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being split.
+    /// * `delim` - The delimiter separating fields. Must not be empty.
+    ///
+    /// # Output
+    ///
+    /// * `Split` - An iterator over the fields of the base string in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::split_iter;
+    ///
+    /// // result = ["a", "b", ""]
+    /// let result: Vec<&str> = split_iter("a,b,", ",").collect();
+    /// ```
+    pub fn split_iter<'a>(base: &'a str, delim: &'a str) -> Split<'a> {
+        assert!(!delim.is_empty());
+        Split {
+            remainder: Some(base),
+            delim
+        }
+    }
+
+    /// # Description
+    ///
+    /// Gets the count of the given characters, tallying only within a byte sub-range of the base string.
+    /// The range is exclusive of its end, and both ends must lie on char boundaries.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `range` - The `(start, end)` byte range to search within.
+    /// * `chars` - The characters being searched and tallied.
+    ///
+    /// # Output
+    ///
+    /// * HashMap<char, usize> - A hashmap containing each character and the number of times it appears in the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, reversed, or does not fall on char boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::count_chars_in_range;
+    ///
+    /// // result = {'a': 1}
+    /// let result = count_chars_in_range(&"xaxaxa", (1, 3), &['a']);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn count_chars_in_range<B>(
+        base: &B,
+        range: (usize, usize),
+        chars: &[char]
+    ) -> HashMap<char, usize>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let (start, end) = range;
+        assert!(start <= end, "byte range start {} is after its end {}", start, end);
+        assert!(end <= binding.len(), "byte range end {} is past the input length {}", end, binding.len());
+        assert!(binding.is_char_boundary(start) && binding.is_char_boundary(end), "byte range ({}, {}) is not on char boundaries", start, end);
+        let mut char_count: HashMap<char, usize> = chars.iter().map(|c_char| (*c_char, 0)).collect();
+        for c_char in binding[start..end].chars() {
+            if let Some(count) = char_count.get_mut(&c_char) {
+                *count += 1;
+            }
+        }
+        char_count
+    }
+
+    /// # Description
+    ///
+    /// Calculates the edit distance needed to turn `a` into `b` with a separate cost for each operation.
+    /// Passing `(1, 1, 1)` gives the standard Levenshtein distance, while e.g. a high `sub` cost makes an insert plus a delete the cheaper way to change a char.
+    /// Strings are compared by char.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The string being transformed.
+    /// * `b` - The target string.
+    /// * `ins` - The cost of inserting a char of `b`.
+    /// * `del` - The cost of deleting a char of `a`.
+    /// * `sub` - The cost of substituting a char of `a` with a different char of `b`.
+    ///
+    /// # Output
+    ///
+    /// * `usize` - The cheapest total cost of the edits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::levenshtein_weighted;
+    ///
+    /// // result = 3
+    /// let result = levenshtein_weighted(&"kitten", &"sitting", 1, 1, 1);
+    /// ```
+    pub fn levenshtein_weighted<A, B>(
+        a: &A,
+        b: &B,
+        ins: usize,
+        del: usize,
+        sub: usize
+    ) -> usize
+        where A: ToString, B: ToString
+    {
+        let a: Vec<char> = a.to_string().chars().collect();
+        let b: Vec<char> = b.to_string().chars().collect();
+        let mut prev: Vec<usize> = (0..=b.len()).map(|j| j * ins).collect();
+        let mut curr: Vec<usize> = vec![0; b.len() + 1];
+
+        for i in 1..=a.len() {
+            curr[0] = i * del;
+            for j in 1..=b.len() {
+                let substitute = match a[i - 1] == b[j - 1] {
+                    true => prev[j - 1],
+                    false => prev[j - 1] + sub
+                };
+                let delete = prev[j] + del;
+                let insert = curr[j - 1] + ins;
+                curr[j] = substitute.min(delete).min(insert);
+            }
+            core::mem::swap(&mut prev, &mut curr);
+        }
+        prev[b.len()]
+    }
+
+    /// # Description
+    ///
+    /// Counts the set bits across all bytes of the base string, e.g. for checksums and bit density analysis.
+    /// With the `simd` feature enabled the bytes are loaded 64 at a time and counted per lane, and the zero padded tail adds no bits.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string whose bytes are counted.
+    ///
+    /// # Output
+    ///
+    /// * `usize` - The total number of set bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::popcount;
+    ///
+    /// // result = 7, 'a' is 0b0110_0001 and 'c' is 0b0110_0011
+    /// let result = popcount(&"ac");
+    /// ```
+    pub fn popcount<B>(base: &B) -> usize
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let bytes = binding.as_bytes();
+        #[cfg(feature = "simd")]
+        {
+            let mut count = 0usize;
+            let mut offset = 0usize;
+            while offset < bytes.len() {
+                let chunk = simd_u8x64_from_offset(bytes, offset);
+                count += chunk.count_ones().cast::<u16>().reduce_sum() as usize;
+                offset += 64;
+            }
+            count
+        }
+        #[cfg(not(feature = "simd"))]
+        bytes.iter().map(|byte| byte.count_ones() as usize).sum()
+    }
+
+    /// # Description
+    ///
+    /// Finds the byte range of the Nth line of the base string, e.g. to print the offending line in a parser error.
+    /// Lines are split like `str::lines`: on `\n` or `\r\n`, the line ending is not part of the range, and a final line ending does not start an empty line.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `index` - The 0-based index of the line.
+    ///
+    /// # Output
+    ///
+    /// * `Option<(usize, usize)>` - The `(start, end)` byte range of the line, end exclusive, or `None` if there are not enough lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::line_at;
+    ///
+    /// // result = Some((5, 8))
+    /// let result = line_at(&"one\r\ntwo\nthree", 1);
+    /// ```
+    pub fn line_at<B>(base: &B, index: usize) -> Option<(usize, usize)>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let bytes = binding.as_bytes();
+        let mut start = 0usize;
+        for _ in 0..index {
+            start += bytes[start..].iter().position(|byte| *byte == b'\n')? + 1;
+        }
+        if start == bytes.len() {
+            return None;
+        }
+        let end = match bytes[start..].iter().position(|byte| *byte == b'\n') {
+            Some(newline) => start + newline,
+            None => bytes.len()
+        };
+        match end > start && bytes[end - 1] == b'\r' {
+            true => Some((start, end - 1)),
+            false => Some((start, end))
+        }
+    }
+
+    /// # Description
+    ///
+    /// Converts a byte offset of the base string into the 0-based line and column it falls on, e.g. to report where a parser error happened.
+    /// Lines end at `\n`, so `\r\n` line endings are handled, and the column is counted in bytes from the start of the line.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base string being searched.
+    /// * `byte_index` - The byte offset being converted. The length of the base string is accepted, pointing just past its end.
+    ///
+    /// # Output
+    ///
+    /// * `Option<(usize, usize)>` - The `(line, column)` of the offset, or `None` if it is past the end of the base string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::byte_to_line_col;
+    ///
+    /// // result = Some((1, 2))
+    /// let result = byte_to_line_col(&"one\r\ntwo", 7);
+    /// ```
+    pub fn byte_to_line_col<B>(base: &B, byte_index: usize) -> Option<(usize, usize)>
+        where B: ToString
+    {
+        let binding = base.to_string();
+        let bytes = binding.as_bytes();
+        if byte_index > bytes.len() {
+            return None;
+        }
+        let before = &bytes[..byte_index];
+        let line = before.iter().filter(|byte| **byte == b'\n').count();
+        let column = match before.iter().rposition(|byte| *byte == b'\n') {
+            Some(newline) => byte_index - newline - 1,
+            None => byte_index
+        };
+        Some((line, column))
+    }
+
+    /// # Description
+    ///
+    /// Finds the first occurrence of a sub-string and returns it together with up to `before` chars in front of it and `after` chars behind it,
+    /// like a single `grep -C` hit, e.g. for search result previews. The context is counted in chars and clamped at the ends of the base string.
+    /// An empty needle never matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The base string being searched.
+    /// * `needle` - The sub-string being looked for.
+    /// * `before` - The most chars of context to keep in front of the match.
+    /// * `after` - The most chars of context to keep behind the match.
+    ///
+    /// # Output
+    ///
+    /// * `Option<String>` - The match with its context, or `None` if the needle does not occur.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use string_simple::compare::find_with_context;
+    ///
+    /// // result = Some("ick brown fo")
+    /// let result = find_with_context(&"the quick brown fox", &"brown", 4, 3);
+    /// ```
+    pub fn find_with_context<B, S>(
+        haystack: &B,
+        needle: &S,
+        before: usize,
+        after: usize
+    ) -> Option<String>
+        where B: ToString, S: ToString
+    {
+        let haystack = haystack.to_string();
+        let needle = needle.to_string();
+        if needle.is_empty() {
+            return None;
+        }
+        let match_start = haystack.find(needle.as_str())?;
+        let match_end = match_start + needle.len();
+        let start = match before {
+            0 => match_start,
+            _ => haystack[..match_start].char_indices().rev().nth(before - 1).map_or(0, |(index, _)| index)
+        };
+        let end = haystack[match_end..].char_indices().nth(after).map_or(haystack.len(), |(index, _)| match_end + index);
+        Some(haystack[start..end].to_string())
+    }
+
+    pub mod multi {
+        use alloc::collections::{BTreeMap, VecDeque};
+        use alloc::string::ToString;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        /// # Description
+        /// An Aho-Corasick automaton for matching a fixed set of patterns simultaneously.
+        /// The goto, failure and output tables are built once in `new` and can be reused for any number of haystacks,
+        /// so scanning for N patterns costs a single pass instead of N separate scans.
+        /// Patterns are matched byte-wise and all positions are byte offsets. Empty patterns never match.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use string_simple::compare::multi::MultiMatcher;
+        ///
+        /// let matcher = MultiMatcher::new(&["he", "she", "his", "hers"]);
+        ///
+        /// // result = [(1, 4, 1), (2, 4, 0), (2, 6, 3)]
+        /// let result = matcher.find_all(&"ushers");
+        /// ```
+        pub struct MultiMatcher {
+            goto: Vec<BTreeMap<u8, usize>>,
+            failure: Vec<usize>,
+            output: Vec<Vec<usize>>,
+            pattern_lens: Vec<usize>
+        }
+
+        impl MultiMatcher {
+            pub fn new(patterns: &[&str]) -> Self {
+                let mut goto: Vec<BTreeMap<u8, usize>> = vec![BTreeMap::new()];
+                let mut output: Vec<Vec<usize>> = vec![vec![]];
+                let mut pattern_lens = Vec::with_capacity(patterns.len());
+
+                for (index, pattern) in patterns.iter().enumerate() {
+                    pattern_lens.push(pattern.len());
+                    if pattern.is_empty() {
+                        continue;
+                    }
+                    let mut state = 0usize;
+                    for byte in pattern.as_bytes() {
+                        state = match goto[state].get(byte) {
+                            Some(next) => *next,
+                            None => {
+                                let next = goto.len();
+                                goto.push(BTreeMap::new());
+                                output.push(vec![]);
+                                goto[state].insert(*byte, next);
+                                next
+                            }
+                        };
+                    }
+                    output[state].push(index);
+                }
+
+                let mut failure = vec![0usize; goto.len()];
+                let mut queue: VecDeque<usize> = goto[0].values().copied().collect();
+                while let Some(state) = queue.pop_front() {
+                    let transitions: Vec<(u8, usize)> = goto[state].iter().map(|(byte, next)| (*byte, *next)).collect();
+                    for (byte, next) in transitions {
+                        queue.push_back(next);
+                        let mut fallback = failure[state];
+                        while fallback != 0 && !goto[fallback].contains_key(&byte) {
+                            fallback = failure[fallback];
+                        }
+                        failure[next] = goto[fallback].get(&byte).copied().unwrap_or(0);
+                        let inherited = output[failure[next]].clone();
+                        output[next].extend(inherited);
+                    }
+                }
+
+                MultiMatcher {
+                    goto,
+                    failure,
+                    output,
+                    pattern_lens
+                }
+            }
+
+            /// # Description
+            /// Finds every occurrence of every pattern in the haystack, including overlapping occurrences.
+            ///
+            /// # Arguments
+            /// * `haystack` - The base string being searched.
+            ///
+            /// # Output
+            /// * `Vec<(usize, usize, usize)>` - A vector of tuples containing the start position, end position and the index of the matched pattern, sorted by start position.
+            pub fn find_all<B>(&self, haystack: &B) -> Vec<(usize, usize, usize)>
+                where B: ToString
+            {
+                let binding = haystack.to_string();
+                let mut matches: Vec<(usize, usize, usize)> = vec![];
+                self.scan(binding.as_bytes(), |start, end, index| matches.push((start, end, index)));
+                matches.sort();
+                matches
+            }
+
+            /// # Description
+            /// Checks whether any of the patterns occur in the haystack, stopping at the first match.
+            ///
+            /// # Arguments
+            /// * `haystack` - The base string being searched.
+            ///
+            /// # Output
+            /// * `bool` - Whether any pattern was found.
+            pub fn is_match<B>(&self, haystack: &B) -> bool
+                where B: ToString
+            {
+                let binding = haystack.to_string();
+                let mut state = 0usize;
+                for byte in binding.as_bytes() {
+                    state = self.next_state(state, *byte);
+                    if !self.output[state].is_empty() {
+                        return true;
+                    }
+                }
+                false
+            }
+
+            pub(super) fn scan<F>(&self, haystack: &[u8], mut on_match: F)
+                where F: FnMut(usize, usize, usize)
+            {
+                let mut state = 0usize;
+                for (pos, byte) in haystack.iter().enumerate() {
+                    state = self.next_state(state, *byte);
+                    for index in &self.output[state] {
+                        on_match(pos + 1 - self.pattern_lens[*index], pos + 1, *index);
+                    }
+                }
+            }
+
+            fn next_state(&self, mut state: usize, byte: u8) -> usize {
+                while state != 0 && !self.goto[state].contains_key(&byte) {
+                    state = self.failure[state];
+                }
+                self.goto[state].get(&byte).copied().unwrap_or(0)
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    #[cfg(feature = "std")]
+    use std::collections::HashMap;
+    use std::ops::ControlFlow;
+    use std::fmt::Display;
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_substring_char_group_count() {
+        let str1 = String::from("aabbccba");
+        let char_group = vec!['c', 'a', 'b'];
+        let result = compare::substring_char_group_count(&str1, &char_group);
+        let mut expected: HashMap<String, usize> = HashMap::new();
+        expected.insert("cba".to_string(), 1);
+        expected.insert("abbc".to_string(), 1);
+        expected.insert("abbccba".to_string(), 1);
+        expected.insert("bbccba".to_string(), 1);
+        expected.insert("ccba".to_string(), 1);
+        expected.insert("aabbccb".to_string(), 1);
+        expected.insert("aabbc".to_string(), 1);
+        expected.insert("abbccb".to_string(), 1);
+        expected.insert("abbcc".to_string(), 1);
+        expected.insert("bccba".to_string(), 1);
+        expected.insert("aabbcc".to_string(), 1);
+        expected.insert("aabbccba".to_string(), 1);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_char_count() {
+        let str1 = String::from("abbccc748237489237498237482374982374892734987423982734982347984732984ccc");
+        let chars = vec!['a', 'b', 'c'];
+        let result = compare::count_chars(&str1, &chars);
+        let mut expected: HashMap<char, usize> = HashMap::new();
+        expected.insert('c', 6);
+        expected.insert('a', 1);
+        expected.insert('b', 2);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_char_count_simd() {
+        let str1 = String::from("abbccc748237489237498237482374982374892734987423982734982347984732984ccc");
+        let chars = vec!['a', 'b', 'c'];
+        let result = compare::count_chars_simd(&str1, &chars);
+        let mut expected: HashMap<char, usize> = HashMap::new();
+        expected.insert('c', 6);
+        expected.insert('a', 1);
+        expected.insert('b', 2);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut str1 = String::from("123");
+        let str2 = String::from("test");
+        modify::append(&mut str1, &str2);
+        assert_eq!(String::from("123test"), str1);
+    }
+
+    #[test]
+    fn test_replace() {
+        let mut str1 = String::from("123123123test123123123test12teest");
+        let str2 = String::from("test");
+        let str3 = String::from("replaced");
+        modify::replace(&mut str1, &str2, &str3);
+        assert_eq!("123123123replaced123123123replaced12teest", str1);
+    }
+
+    #[test]
+    fn test_strip_prefix() {
+        let mut str1 = String::from("www.example.com");
+        assert!(modify::strip_prefix(&mut str1, &"www."));
+        assert_eq!("example.com", str1);
+
+        let mut str2 = String::from("example.com");
+        assert!(!modify::strip_prefix(&mut str2, &"www."));
+        assert_eq!("example.com", str2);
+    }
+
+    #[test]
+    fn test_strip_suffix() {
+        let mut str1 = String::from("archive.tar.gz");
+        assert!(modify::strip_suffix(&mut str1, &".gz"));
+        assert_eq!("archive.tar", str1);
+
+        let mut str2 = String::from("archive.tar");
+        assert!(!modify::strip_suffix(&mut str2, &".gz"));
+        assert_eq!("archive.tar", str2);
+    }
+
+    #[test]
+    fn test_repeat() {
+        let mut str1 = String::from("ab");
+        modify::repeat(&mut str1, 3);
+        assert_eq!("ababab", str1);
+
+        let mut str2 = String::from("ab");
+        modify::repeat(&mut str2, 1);
+        assert_eq!("ab", str2);
+
+        modify::repeat(&mut str2, 0);
+        assert_eq!("", str2);
+    }
+
+    #[test]
+    fn test_replace_cow() {
+        let str1 = "123test123test";
+        let result = modify::replace_cow(str1, &"test", &"replaced");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!("123replaced123replaced", result);
+
+        let result = modify::replace_cow(str1, &"missing", &"replaced");
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert_eq!(str1, result);
+    }
+
+    #[test]
+    fn test_make_title_case() {
+        let mut str1 = String::from("hELLO wORLD");
+        modify::make_title_case(&mut str1);
+        assert_eq!("Hello World", str1);
+
+        let mut str2 = String::from("don't  stop\tjean-luc");
+        modify::make_title_case(&mut str2);
+        assert_eq!("Don't  Stop\tJean-luc", str2);
+
+        let mut str3 = String::from("élan ÉCOLE");
+        modify::make_title_case(&mut str3);
+        assert_eq!("Élan École", str3);
+    }
+
+    #[test]
+    fn test_center() {
+        let mut str1 = String::from("hi");
+        modify::center(&mut str1, 6, '*');
+        assert_eq!("**hi**", str1);
+
+        let mut str2 = String::from("hi");
+        modify::center(&mut str2, 5, '-');
+        assert_eq!("-hi--", str2);
+
+        let mut str3 = String::from("wide");
+        modify::center(&mut str3, 2, '-');
+        assert_eq!("wide", str3);
+    }
+
+    #[test]
+    fn test_replace_where() {
+        let mut str1 = String::from("xxxx");
+        modify::replace_where(&mut str1, &"x", |index| index % 2 == 0, |_| String::from("y"));
+        assert_eq!("yxyx", str1);
+
+        let mut str2 = String::from("a-b-c");
+        modify::replace_where(&mut str2, &"-", |_| true, |index| format!("<{}>", index));
+        assert_eq!("a<0>b<1>c", str2);
+    }
+
+    #[test]
+    fn test_remove_chars() {
+        let mut str1 = String::from("banana apple");
+        modify::remove_chars(&mut str1, &['a', 'e']);
+        assert_eq!("bnn ppl", str1);
+
+        let mut str2 = String::from("café 123");
+        modify::remove_chars(&mut str2, &['é', '1', '2', '3']);
+        assert_eq!("caf ", str2);
+    }
+
+    #[test]
+    fn test_trim_cow() {
+        let str1 = String::from("already trimmed");
+        let (ptr, capacity) = (str1.as_ptr(), str1.capacity());
+        let result = modify::trim_cow(str1);
+        assert_eq!("already trimmed", result);
+        assert_eq!(ptr, result.as_ptr());
+        assert_eq!(capacity, result.into_owned().capacity());
+
+        let str2 = String::from("  padded \n");
+        let ptr = str2.as_ptr();
+        let result = modify::trim_cow(str2);
+        assert_eq!("padded", result);
+        assert_eq!(ptr, result.as_ptr());
+
+        let result = modify::trim_cow(" borrowed ");
+        assert!(matches!(result, Cow::Borrowed("borrowed")));
+    }
+
+    #[test]
+    fn test_expand_tabs() {
+        let mut str1 = String::from("a\tb");
+        modify::expand_tabs(&mut str1, 4);
+        assert_eq!("a   b", str1);
+
+        let mut str2 = String::from("\tab\tc\nabcd\te");
+        modify::expand_tabs(&mut str2, 4);
+        assert_eq!("    ab  c\nabcd    e", str2);
+
+        let mut str3 = String::from("a\tb");
+        modify::expand_tabs(&mut str3, 0);
+        assert_eq!("ab", str3);
+    }
+
+    #[test]
+    fn test_replace_with() {
+        let mut str1 = String::from("nnn");
+        let mut counter = 0;
+        modify::replace_with(&mut str1, &"n", |_| {
+            counter += 1;
+            counter.to_string()
+        });
+        assert_eq!("123", str1);
+
+        let mut str2 = String::from("ab-ab");
+        modify::replace_with(&mut str2, &"ab", |matched| matched.to_uppercase());
+        assert_eq!("AB-AB", str2);
+    }
+
+    #[test]
+    fn test_swap_case() {
+        let mut base = String::from("Hello World");
+        modify::swap_case(&mut base);
+        assert_eq!("hELLO wORLD", base);
+        let mut base = String::from("Ünïcode 123 aB");
+        modify::swap_case(&mut base);
+        assert_eq!("ÜNïCODE 123 Ab", base);
+    }
+
+    #[test]
+    fn test_ensure_prefix_suffix() {
+        let mut base = String::from("dir");
+        modify::ensure_suffix(&mut base, &"/");
+        assert_eq!("dir/", base);
+        modify::ensure_suffix(&mut base, &"/");
+        assert_eq!("dir/", base);
+        modify::ensure_prefix(&mut base, &"/");
+        assert_eq!("/dir/", base);
+        modify::ensure_prefix(&mut base, &"/");
+        assert_eq!("/dir/", base);
+        modify::ensure_prefix(&mut base, &"");
+        assert_eq!("/dir/", base);
+    }
+
+    #[test]
+    fn test_truncate_ellipsis() {
+        let mut base = String::from("Hello World");
+        modify::truncate_ellipsis(&mut base, 8, "…");
+        assert_eq!("Hello W…", base);
+        modify::truncate_ellipsis(&mut base, 8, "…");
+        assert_eq!("Hello W…", base);
+        let mut base = String::from("Hello World");
+        modify::truncate_ellipsis(&mut base, 11, "...");
+        assert_eq!("Hello World", base);
+        modify::truncate_ellipsis(&mut base, 7, "...");
+        assert_eq!("Hell...", base);
+        let mut base = String::from("ééééé");
+        modify::truncate_ellipsis(&mut base, 3, "…");
+        assert_eq!("éé…", base);
+        modify::truncate_ellipsis(&mut base, 1, "...");
+        assert_eq!("...", base);
+    }
+
+    #[test]
+    fn test_indent() {
+        let mut base = String::from("a\nb");
+        modify::indent(&mut base, "  ");
+        assert_eq!("  a\n  b", base);
+        let mut base = String::from("fn f() {\n\n}\n");
+        modify::indent(&mut base, "    ");
+        assert_eq!("    fn f() {\n\n    }\n", base);
+        let mut base = String::new();
+        modify::indent(&mut base, "> ");
+        assert_eq!("", base);
+    }
+
+    #[test]
+    fn test_dedent() {
+        let mut base = String::from("    a\n      b");
+        modify::dedent(&mut base);
+        assert_eq!("a\n  b", base);
+        let mut base = String::from("\n    fn f() {\n  \n        x\n    }\n");
+        modify::dedent(&mut base);
+        assert_eq!("\nfn f() {\n\n    x\n}\n", base);
+        let mut base = String::from("\t a\n\tb");
+        modify::dedent(&mut base);
+        assert_eq!(" a\nb", base);
+        let mut base = String::from("a\n  b");
+        modify::dedent(&mut base);
+        assert_eq!("a\n  b", base);
+    }
+
+    #[test]
+    fn test_replace_chars() {
+        let mut base = String::from("abc");
+        modify::replace_chars(&mut base, &[('a', 'A'), ('b', 'B')]);
+        assert_eq!("ABc", base);
+        let mut base = String::from("päth/to\\file");
+        modify::replace_chars(&mut base, &[('\\', '/'), ('\\', '_')]);
+        assert_eq!("päth/to/file", base);
+        let mut base = String::from("crème brûlée");
+        modify::replace_chars(&mut base, &[('è', 'e'), ('û', 'u'), ('é', 'e'), (' ', '_')]);
+        assert_eq!("creme_brulee", base);
+        let mut base = String::from("ab");
+        modify::replace_chars(&mut base, &[('a', 'b'), ('b', 'a')]);
+        assert_eq!("ba", base);
+    }
+
+    #[test]
+    fn test_squeeze() {
+        let mut base = String::from("a   b");
+        modify::squeeze(&mut base, &[' ']);
+        assert_eq!("a b", base);
+        let mut base = String::from("snake__case--name-_-x");
+        modify::squeeze(&mut base, &['-', '_']);
+        assert_eq!("snake_case-name-_-x", base);
+        let mut base = String::from("bookkeeper  ééé");
+        modify::squeeze(&mut base, &['é', 'k']);
+        assert_eq!("bookeeper  é", base);
+    }
+
+    #[test]
+    fn test_chunk_with_newlines() {
+        let mut base = String::from("0123456789");
+        modify::chunk_with_newlines(&mut base, 4);
+        assert_eq!("0123\n4567\n89", base);
+        assert_eq!(2, base.matches('\n').count());
+        let mut base = String::from("abcdef");
+        modify::chunk_with_newlines(&mut base, 3);
+        assert_eq!("abc\ndef\n", base);
+        let mut base = String::from("ééé");
+        modify::chunk_with_newlines(&mut base, 2);
+        assert_eq!("éé\né", base);
+        let mut base = String::new();
+        modify::chunk_with_newlines(&mut base, 2);
+        assert_eq!("", base);
+    }
+
+    #[test]
+    fn test_replace_smart_case() {
+        let mut base = String::from("dog DOG Dog dOG");
+        modify::replace_smart_case(&mut base, &"dog", &"cat");
+        assert_eq!("cat CAT Cat cat", base);
+        let mut base = String::from("Hello hello");
+        modify::replace_smart_case(&mut base, &"HELLO", &"goodbye");
+        assert_eq!("Goodbye goodbye", base);
+        let mut base = String::from("ÉTÉ été");
+        modify::replace_smart_case(&mut base, &"été", &"hiver");
+        assert_eq!("HIVER hiver", base);
+        let mut base = String::from("no match");
+        modify::replace_smart_case(&mut base, &"dog", &"cat");
+        assert_eq!("no match", base);
+    }
+
+    #[test]
+    fn test_caesar_shift() {
+        let mut base = String::from("Hello");
+        modify::rot13(&mut base);
+        assert_eq!("Uryyb", base);
+        let mut base = String::from("Hello, Wörld! zZ");
+        modify::rot13(&mut base);
+        modify::rot13(&mut base);
+        assert_eq!("Hello, Wörld! zZ", base);
+        let mut base = String::from("abc XYZ");
+        modify::caesar_shift(&mut base, -1);
+        assert_eq!("zab WXY", base);
+        modify::caesar_shift(&mut base, 27);
+        assert_eq!("abc XYZ", base);
+    }
+
+    #[test]
+    fn test_replace_between() {
+        let mut base = String::from("a<x>b<y>c");
+        modify::replace_between(&mut base, "<", ">", &"_");
+        assert_eq!("a<_>b<_>c", base);
+
+        let mut base = String::from("a<x>b<y");
+        modify::replace_between(&mut base, "<", ">", &"_");
+        assert_eq!("a<_>b<y", base);
+
+        let mut base = String::from("{{ name }} and {{}}");
+        modify::replace_between(&mut base, "{{", "}}", &"***");
+        assert_eq!("{{***}} and {{***}}", base);
+    }
+
+    #[test]
+    fn test_translate() {
+        let mut base = String::from("abc");
+        modify::translate(&mut base, &['a', 'b'], &['1', '2']);
+        assert_eq!("12c", base);
+
+        let mut base = String::from("über");
+        modify::translate(&mut base, &['ü', 'r'], &['u', 'R']);
+        assert_eq!("ubeR", base);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_translate_length_mismatch() {
+        modify::translate(&mut String::from("abc"), &['a', 'b'], &['1']);
+    }
+
+    #[test]
+    fn test_dedup_words() {
+        let mut base = String::from("hello hello world world world");
+        modify::dedup_words(&mut base);
+        assert_eq!("hello world", base);
+
+        let mut base = String::from("  The the  cat\tcat sat the ");
+        modify::dedup_words(&mut base);
+        assert_eq!("The the cat sat the", base);
+    }
+
+    #[test]
+    fn test_contains() {
+        let str1 = String::from("123123123test123123123");
+        let str2 = String::from("test");
+        let result = compare::contains(&str1, &str2);
+        assert_eq!(true, result);
+    }
+
+    #[test]
+    fn test_contains_simd() {
+        let str1 = String::from("123123123test123123123");
+        let str2 = String::from("test");
+        let result = compare::contains_simd2(&str1, &str2);
+        assert_eq!(true, result);
+    }
+
+    #[test]
+    fn test_contains_simd2() {
+        let str1 = String::from("12312312312312312312312312312312312312t1e3s2t123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123test");
+        let str2 = String::from("test");
+        let result = compare::contains_simd2(&str1, &str2);
+        assert_eq!(true, result);
+    }
+
+    #[test]
+    fn test_contains_simd3() {
+        let str1 = String::from("12312312312312312312312312312312312312t1e3123123123123123111111test12312312312312312312312312312312312312t1e312312312312312311111112312312312312312312312312312312312312t1e3123123123123123111111");
+        let str2 = String::from("test");
+        let result = compare::contains_simd2(&str1, &str2);
+        assert_eq!(true, result);
+    }
+
+    #[test]
+    fn test_contains_simd4() {
+        let str1 = String::from("12312312312312312312312312312312312312t1e3123123123123123111111test123123123123123123test2312312312312312t1e312312312312312311111112312312312312312312312312312312312312t1e312312312312312311111112312312312312312312312312312312312312t1e3123123123123123111111test123123123123123123test2312312312312312t1e312312312312312311111112312312312312312312312312312312312312t1e312312312312312311111112312312312312312312312312312312312312t1e3123123123123123111111test123123123123123123test2312312312312312t1e312312312312312311111112312312312312312312312312312312312312t1e3123123123123123111111");
+        let str2 = String::from("test");
+        let result = compare::sub_count_simd2(&str1, &str2);
+        assert_eq!(6, result);
+    }
+
+    #[test]
+    fn test_find_all_exact() {
+        let str1 = String::from("123test113test444testtest");
+        let str2 = String::from("test");
+        let result = compare::find_all_exact(&str1, &str2);
+        let expected: Vec<(usize, usize)> = vec![(3, 7), (10, 14), (17, 21), (21, 25)];
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_count_many() {
+        let str1 = String::from("foofoobar");
+        let needles = vec!["foo", "bar"];
+        let result = compare::count_many(&str1, &needles);
+        let mut expected: HashMap<String, usize> = HashMap::new();
+        expected.insert("foo".to_string(), 2);
+        expected.insert("bar".to_string(), 1);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multi_matcher_find_all() {
+        let matcher = compare::multi::MultiMatcher::new(&["he", "she", "his", "hers"]);
+        let result = matcher.find_all(&"ushers");
+        let expected: Vec<(usize, usize, usize)> = vec![(1, 4, 1), (2, 4, 0), (2, 6, 3)];
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_multi_matcher_reuse() {
+        let matcher = compare::multi::MultiMatcher::new(&["ab", "b", ""]);
+        assert_eq!(vec![(0, 2, 0), (1, 2, 1)], matcher.find_all(&"ab"));
+        assert_eq!(vec![(0, 1, 1), (1, 2, 1), (2, 4, 0), (3, 4, 1)], matcher.find_all(&"bbab"));
+        assert!(matcher.find_all(&"xyz").is_empty());
+    }
+
+    #[test]
+    fn test_is_palindrome() {
+        assert!(compare::is_palindrome(&"level"));
+        assert!(compare::is_palindrome(&"résér"));
+        assert!(!compare::is_palindrome(&"hello"));
+        assert!(!compare::is_palindrome(&"A man a plan a canal Panama"));
+        assert!(compare::is_palindrome_ignore(&"A man a plan a canal Panama"));
+        assert!(!compare::is_palindrome_ignore(&"hello"));
+    }
+
+    #[test]
+    fn test_longest_common_suffix() {
+        assert_eq!("ing", compare::longest_common_suffix(&["running", "jumping", "singing"]));
+        assert_eq!("only", compare::longest_common_suffix(&["only"]));
+        assert_eq!("", compare::longest_common_suffix::<&str>(&[]));
+        assert_eq!("", compare::longest_common_suffix(&["abc", "xyz"]));
+        assert_eq!("é", compare::longest_common_suffix(&["café", "é"]));
+    }
+
+    #[test]
+    fn test_byte_histogram() {
+        let mut str1 = String::from("héllo");
+        for _ in 0..20 {
+            str1.push_str("abcabc");
+        }
+        let result = compare::byte_histogram(&str1);
+        assert_eq!(40, result[b'a' as usize]);
+        assert_eq!(2, result[b'l' as usize]);
+        assert_eq!(1, result[0xC3]);
+        assert_eq!(1, result[0xA9]);
+        assert_eq!(0, result[0]);
+        assert_eq!(str1.len(), result.iter().sum::<usize>());
+    }
+
+    #[test]
+    fn test_levenshtein_within() {
+        assert_eq!(None, compare::levenshtein_within(&"kitten", &"sitting", 2));
+        assert_eq!(Some(3), compare::levenshtein_within(&"kitten", &"sitting", 3));
+        assert_eq!(Some(3), compare::levenshtein_within(&"kitten", &"sitting", 10));
+        assert_eq!(Some(0), compare::levenshtein_within(&"same", &"same", 0));
+        assert_eq!(Some(4), compare::levenshtein_within(&"", &"four", 4));
+        assert_eq!(None, compare::levenshtein_within(&"", &"four", 3));
+        assert_eq!(Some(1), compare::levenshtein_within(&"café", &"cafe", 1));
+        assert_eq!(Some(2), compare::levenshtein_within(&"abcdef", &"bcdefa", 2));
+    }
+
+    #[test]
+    fn test_word_count() {
+        let str1 = String::from("  one two\tthree\n");
+        assert_eq!(3, compare::word_count(&str1));
+        assert_eq!(3, compare::word_count_simd(&str1));
+        assert_eq!(0, compare::word_count_simd(&""));
+        assert_eq!(0, compare::word_count_simd(&" \n\t "));
+    }
+
+    #[test]
+    fn test_word_count_simd_random() {
+        for len in [1usize, 63, 64, 65, 127, 128, 129, 1000, 4099] {
+            let str1 = pseudo_random_ascii(len as u64, len, b"ab \n\tc\rd");
+            assert_eq!(compare::word_count(&str1), compare::word_count_simd(&str1));
+        }
+    }
+
+    #[test]
+    fn test_contains_any() {
+        assert!(compare::contains_any(&"I have a dog", &["cat", "dog"]));
+        assert!(!compare::contains_any(&"I have a bird", &["cat", "dog"]));
+        assert!(!compare::contains_any::<&str, &str>(&"I have a dog", &[]));
+        assert!(compare::contains_any(&"I have a bird", &["cat", ""]));
+    }
+
+    #[test]
+    fn test_find_any() {
+        assert_eq!(Some((2, 4, 1)), compare::find_any(&"a != b", &["==", "!=", "<"]));
+        assert_eq!(Some((2, 3, 0)), compare::find_any(&"a <= b", &["<", "<="]));
+        assert_eq!(Some((2, 4, 0)), compare::find_any(&"a <= b", &["<=", "<"]));
+        assert_eq!(None, compare::find_any(&"a + b", &["==", "!=", ""]));
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(vec!["a", "b", "c"], compare::split(&"a,b,c", &","));
+        assert_eq!(vec![""], compare::split(&"", &","));
+        assert_eq!(vec!["abc"], compare::split(&"abc", &","));
+        assert_eq!(vec!["a", "", "b", ""], compare::split(&"a::::b::", &"::"));
+    }
+
+    #[test]
+    fn test_last_index_of_byte_simd() {
+        let mut str1 = String::new();
+        for _ in 0..20 {
+            str1.push_str("/some/long/directory");
+        }
+        str1.push_str("/file.txt");
+        assert_eq!(str1.rfind('/'), compare::last_index_of_byte_simd(&str1, b'/'));
+        assert_eq!(Some(0), compare::last_index_of_byte_simd(&"/aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", b'/'));
+        assert_eq!(None, compare::last_index_of_byte_simd(&str1, b'\\'));
+        assert_eq!(None, compare::last_index_of_byte_simd(&"", b'/'));
+    }
+
+    #[test]
+    fn test_char_at() {
+        let str1 = String::from("héllo");
+        assert_eq!(Some('h'), compare::char_at(&str1, 0));
+        assert_eq!(Some('é'), compare::char_at(&str1, 1));
+        assert_eq!(None, compare::char_at(&str1, 2));
+        assert_eq!(Some('l'), compare::char_at(&str1, 3));
+        assert_eq!(None, compare::char_at(&str1, str1.len()));
+        assert_eq!(None, compare::char_at(&str1, 100));
+    }
+
+    #[test]
+    fn test_merge_ranges() {
+        assert_eq!(vec![(0, 8), (10, 12)], compare::merge_ranges(&[(0, 4), (4, 8), (10, 12)]));
+        assert_eq!(vec![(0, 8), (10, 12)], compare::merge_ranges(&[(10, 12), (2, 8), (0, 3)]));
+        assert_eq!(vec![(1, 9)], compare::merge_ranges(&[(1, 9), (2, 3)]));
+        assert!(compare::merge_ranges(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_char_indices() {
+        let result = compare::char_indices(&"aé😀b");
+        assert_eq!(vec![(0, 'a'), (1, 'é'), (3, '😀'), (7, 'b')], result);
+        assert!(compare::char_indices(&"").is_empty());
+    }
+
+    #[test]
+    fn test_jaccard() {
+        assert_eq!(1.0, compare::jaccard(&"hello world", &"hello world", 3));
+        assert_eq!(0.0, compare::jaccard(&"abc", &"xyz", 1));
+        assert_eq!(1.0 / 3.0, compare::jaccard(&"abc", &"abd", 2));
+        assert_eq!(1.0, compare::jaccard(&"abc", &"cab", 1));
+        assert_eq!(1.0, compare::jaccard(&"a", &"b", 2));
+        assert_eq!(1.0 / 3.0, compare::jaccard(&"éa", &"éb", 1));
+    }
+
+    #[test]
+    fn test_similarity_ratio() {
+        assert_eq!(1.0, compare::similarity_ratio(&"abc", &"abc"));
+        assert_eq!(0.0, compare::similarity_ratio(&"abc", &"xyz"));
+        assert_eq!(0.75, compare::similarity_ratio(&"abcd", &"abxd"));
+        assert_eq!(0.5, compare::similarity_ratio(&"ab", &"abcd"));
+        assert_eq!(1.0, compare::similarity_ratio(&"", &""));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_count_chars_ignore_case() {
+        let result = compare::count_chars_ignore_case(&"aAaA", &['a']);
+        let mut expected: HashMap<char, usize> = HashMap::new();
+        expected.insert('a', 4);
+        assert_eq!(result, expected);
+
+        let result = compare::count_chars_ignore_case(&"Bébé É", &['B', 'é', 'z']);
+        let mut expected: HashMap<char, usize> = HashMap::new();
+        expected.insert('b', 2);
+        expected.insert('é', 2);
+        expected.insert('z', 0);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ascii_char_set() {
+        let char_set = compare::AsciiCharSet::from_chars(&['a', 'z', '\n']).unwrap();
+        assert!(char_set.contains(b'a'));
+        assert!(char_set.contains(b'z'));
+        assert!(char_set.contains(b'\n'));
+        assert!(!char_set.contains(b'b'));
+        assert!(!char_set.contains(0xFF));
+        assert!(compare::AsciiCharSet::from_chars(&['a', 'é']).is_none());
+    }
+
+    #[test]
+    fn test_for_each_match() {
+        let str1 = String::from("123test113test444testtest");
+        let mut seen: Vec<(usize, usize)> = vec![];
+        compare::for_each_match(&str1, &"test", |start, end| {
+            seen.push((start, end));
+            match seen.len() {
+                2 => ControlFlow::Break(()),
+                _ => ControlFlow::Continue(())
+            }
+        });
+        assert_eq!(vec![(3, 7), (10, 14)], seen);
+
+        let mut count = 0usize;
+        compare::for_each_match(&"aaaa", &"aa", |_, _| {
+            count += 1;
+            ControlFlow::Continue(())
+        });
+        assert_eq!(3, count);
+    }
+
+    #[test]
+    fn test_to_char_range() {
+        let str1 = String::from("héllo");
+        assert_eq!((2, 5), compare::to_char_range(&str1, (3, 6)));
+        assert_eq!((1, 2), compare::to_char_range(&str1, (1, 3)));
+        assert_eq!((0, 0), compare::to_char_range(&str1, (0, 0)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_char_range_mid_char() {
+        compare::to_char_range(&"héllo", (2, 4));
+    }
+
+    #[test]
+    fn test_soundex() {
+        assert_eq!("R163", compare::soundex(&"Robert"));
+        assert_eq!("R163", compare::soundex(&"Rupert"));
+        assert_eq!("A261", compare::soundex(&"Ashcraft"));
+        assert_eq!("T522", compare::soundex(&"Tymczak"));
+        assert_eq!("P236", compare::soundex(&"Pfister"));
+        assert_eq!("L000", compare::soundex(&"Lee"));
+        assert_eq!("", compare::soundex(&"123"));
+    }
+
+    #[test]
+    fn test_find_all_detailed() {
+        let result = compare::find_all_detailed(&"123test113test444testtest", &"test");
+        assert_eq!(result.count(), result.ranges().len());
+        assert_eq!(4, result.count());
+        assert_eq!(16, result.total_bytes());
+        assert_eq!(&[(3, 7), (10, 14), (17, 21), (21, 25)], result.ranges());
+
+        let result = compare::find_all_detailed(&"abc", &"x");
+        assert_eq!(0, result.count());
+        assert_eq!(0, result.total_bytes());
+    }
+
+    #[test]
+    fn test_contains_matches_scalar_reference() {
+        for len in [5usize, 63, 64, 65, 200, 1000] {
+            let str1 = pseudo_random_ascii(len as u64, len, b"abct");
+            for needle in ["test", "tt", "a", "cab", "ttttt"] {
+                assert_eq!(str1.contains(needle), compare::contains(&str1, &needle));
+            }
+        }
+        let mut str2 = "0123456789abcdef".repeat(1024);
+        assert!(!compare::contains(&str2, &"test"));
+        str2.push_str("test");
+        assert!(compare::contains(&str2, &"test"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_substring_char_group_count_bounded() {
+        let str1 = String::from("aabbccba");
+        let char_group = vec!['c', 'a', 'b'];
+        let result = compare::substring_char_group_count_bounded(&str1, &char_group, 3);
+        let mut expected: HashMap<String, usize> = HashMap::new();
+        expected.insert("cba".to_string(), 1);
+        assert_eq!(result, expected);
+        assert!(result.keys().all(|key| key.chars().count() <= 3));
+
+        let unbounded = compare::substring_char_group_count(&str1, &char_group);
+        assert_eq!(unbounded, compare::substring_char_group_count_bounded(&str1, &char_group, str1.len()));
+    }
+
+    fn pseudo_random_ascii(seed: u64, len: usize, alphabet: &[u8]) -> String {
+        let mut state = seed.wrapping_mul(0x9E3779B97F4A7C15) | 1;
+        let mut random = String::with_capacity(len);
+        for _ in 0..len {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            random.push(alphabet[(state >> 32) as usize % alphabet.len()] as char);
+        }
+        random
+    }
+
+    fn apply_edit_ops(base: &str, ops: &[compare::EditOp]) -> String {
+        let mut chars = base.chars();
+        let mut result = String::new();
+        for op in ops {
+            match op {
+                compare::EditOp::Match => result.push(chars.next().unwrap()),
+                compare::EditOp::Substitute(_, c) => {
+                    chars.next();
+                    result.push(*c);
+                }
+                compare::EditOp::Insert(c) => result.push(*c),
+                compare::EditOp::Delete(_) => {
+                    chars.next();
+                }
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_edit_ops() {
+        let ops = compare::edit_ops(&"kitten", &"sitting");
+        assert_eq!("sitting", apply_edit_ops("kitten", &ops));
+        assert_eq!(3, ops.iter().filter(|op| **op != compare::EditOp::Match).count());
+
+        let ops = compare::edit_ops(&"", &"ab");
+        assert_eq!(vec![compare::EditOp::Insert('a'), compare::EditOp::Insert('b')], ops);
+
+        let ops = compare::edit_ops(&"héllo", &"hello!");
+        assert_eq!("hello!", apply_edit_ops("héllo", &ops));
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_grapheme_count() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let flag = "\u{1F1E8}\u{1F1E6}";
+        assert_eq!(1, compare::grapheme_count(&family));
+        assert_eq!(1, compare::grapheme_count(&flag));
+        assert_eq!(5, family.chars().count());
+        assert_eq!(3, compare::grapheme_count(&"ae\u{301}b"));
+        assert_eq!(0, compare::grapheme_count(&""));
+    }
+
+    #[test]
+    fn test_split_on_any() {
+        let base = "a, b;c";
+        let fields = compare::split_on_any(&base, &[',', ';', ' ']);
+        assert_eq!(vec![(0, 1), (3, 4), (5, 6)], fields);
+        let words: Vec<&str> = fields.iter().map(|&(s, e)| &base[s..e]).collect();
+        assert_eq!(vec!["a", "b", "c"], words);
+        assert_eq!(vec![(0, 1), (2, 2), (3, 4), (5, 6)], compare::split_on_any_with(&base, &[',', ';', ' '], false));
+        assert_eq!(vec![(0, 0), (1, 1)], compare::split_on_any_with(&",", &[','], false));
+        assert!(compare::split_on_any(&",;", &[',', ';']).is_empty());
+        assert_eq!(vec![(0, 2), (3, 5)], compare::split_on_any(&"été", &['t']));
+    }
+
+    #[test]
+    fn test_find_all_starts_simd() {
+        let base = "This is my test string! test test!";
+        assert_eq!(vec![11, 24, 29], compare::find_all_starts_simd(&base, &"test"));
+        assert_eq!(vec![0, 1, 2], compare::find_all_starts_simd(&"aaaa", &"aa"));
+        assert!(compare::find_all_starts_simd(&"abc", &"abd").is_empty());
+
+        for len in [63usize, 64, 65, 127, 128, 200, 1000] {
+            let haystack = pseudo_random_ascii(len as u64, len, b"abc");
+            for needle in ["a", "ab", "abc", "cab", "aaaa"] {
+                let expected: Vec<usize> = compare::find_all_exact(&haystack, &needle).iter().map(|&(start, _)| start).collect();
+                assert_eq!(expected, compare::find_all_starts_simd(&haystack, &needle));
+            }
+        }
+
+        let mut boundary = "x".repeat(62);
+        boundary.push_str("needle");
+        assert_eq!(vec![62], compare::find_all_starts_simd(&boundary, &"needle"));
+    }
+
+    #[test]
+    fn test_contains_char() {
+        let mut base = "abcdefghijklmnopqrstuvwxy".repeat(100);
+        assert!(!compare::contains_char(&base, 'z'));
+        base.push('z');
+        assert!(compare::contains_char(&base, 'z'));
+        assert!(compare::contains_char(&"café au lait", 'é'));
+        assert!(!compare::contains_char(&"cafe au lait", 'é'));
+        assert!(!compare::contains_char(&"é", 'Ã'));
+        assert!(!compare::contains_char(&"", 'a'));
+    }
+
+    #[test]
+    fn test_is_subsequence() {
+        assert!(compare::is_subsequence(&"ace", &"abcde"));
+        assert!(!compare::is_subsequence(&"aec", &"abcde"));
+        assert!(compare::is_subsequence(&"", &"abc"));
+        assert!(!compare::is_subsequence(&"a", &""));
+        assert!(compare::is_subsequence(&"éü", &"héllo wörld ü"));
+        assert!(!compare::is_subsequence(&"aa", &"a"));
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        let word_starts = compare::fuzzy_score(&"fb", &"foo_bar").unwrap();
+        let inside_word = compare::fuzzy_score(&"fb", &"feedback").unwrap();
+        assert_eq!(49, word_starts);
+        assert_eq!(39, inside_word);
+        assert!(word_starts > inside_word);
+        assert!(compare::fuzzy_score(&"ab", &"ab").unwrap() > compare::fuzzy_score(&"ab", &"axb").unwrap());
+        assert_eq!(Some(50), compare::fuzzy_score(&"fB", &"fooBar"));
+        assert_eq!(None, compare::fuzzy_score(&"fb", &"fooBar"));
+        assert_eq!(None, compare::fuzzy_score(&"bf", &"foo_bar"));
+        assert_eq!(Some(0), compare::fuzzy_score(&"", &"anything"));
+    }
+
+    #[test]
+    fn test_first_index_of_any() {
+        assert_eq!(Some(1), compare::first_index_of_any(&"a;b,c", &[',', ';']));
+        assert_eq!(None, compare::first_index_of_any(&"abc", &[',', ';']));
+        assert_eq!(None, compare::first_index_of_any(&"abc", &[]));
+        assert_eq!(None, compare::first_index_of_any(&"abc", &['\0']));
+        assert_eq!(Some(1), compare::first_index_of_any(&"héa,", &['a', 'é']));
+        assert_eq!(Some(3), compare::first_index_of_any(&"héa,", &['a', ',']));
+
+        let mut long = "x".repeat(130);
+        long.push_str("é;,");
+        assert_eq!(Some(130), compare::first_index_of_any(&long, &['é', ';']));
+        assert_eq!(Some(132), compare::first_index_of_any(&long, &[',', ';']));
+        assert_eq!(long.find([',', ';']), compare::first_index_of_any(&long, &[',', ';']));
+    }
+
+    #[test]
+    fn test_substring_count_scalar_matches_simd() {
+        assert_eq!(2, compare::substring_count_scalar(&"aaaa", &"aa"));
+        assert_eq!(2, compare::substring_count_simd(&"aaaa", &"aa"));
+        assert_eq!(1, compare::substring_count_simd(&"aaa", &"aa"));
+        assert_eq!(0, compare::substring_count_simd(&"abc", &"abd"));
+
+        for len in [1usize, 63, 64, 65, 127, 128, 129, 500, 4096] {
+            let haystack = pseudo_random_ascii(len as u64, len, b"ab");
+            for needle in ["a", "aa", "ab", "aba", "abab", "aaaaa", "babba"] {
+                if needle.len() > haystack.len() {
+                    continue;
                 }
-                break 'inner;
+                let expected = compare::substring_count_scalar(&haystack, &needle);
+                assert_eq!(expected, compare::substring_count_simd(&haystack, &needle), "needle {needle:?} in {haystack:?}");
+                assert_eq!(expected, compare::substring_count_kmp(&haystack, &needle), "needle {needle:?} in {haystack:?}");
             }
-            current_base_pos += 1;
         }
-        return false;
+
+        let long_haystack = "a".repeat(1000);
+        let long_needle = "a".repeat(300);
+        assert_eq!(3, compare::substring_count_scalar(&long_haystack, &long_needle));
+        assert_eq!(3, compare::substring_count_simd(&long_haystack, &long_needle));
     }
 
-    pub fn contains_simd<B, S>(haystack: &B, needle: &S) -> bool
-        where
-            B: ToString,
-            S: ToString,
-    {
-        let haystack = haystack.to_string().as_bytes();
-        let needle = needle.to_string().as_bytes();
+    #[test]
+    fn test_char_ngrams() {
+        assert_eq!(vec![(0, 2), (1, 3), (2, 4)], compare::char_ngrams(&"abcd", 2));
+        assert_eq!(vec![(0, 4)], compare::char_ngrams(&"abcd", 4));
+        assert!(compare::char_ngrams(&"abc", 4).is_empty());
+        assert!(compare::char_ngrams(&"", 1).is_empty());
+        let base = "héllo";
+        let grams: Vec<&str> = compare::char_ngrams(&base, 2).iter().map(|&(s, e)| &base[s..e]).collect();
+        assert_eq!(vec!["hé", "él", "ll", "lo"], grams);
+    }
 
-        // Ensure preconditions are met: haystack must be larger than needle and needle non-empty
-        assert!(haystack.len() >= needle.len() && !needle.is_empty());
+    #[test]
+    fn test_distinct_char_count() {
+        assert_eq!(3, compare::distinct_char_count(&"aabbc"));
+        assert_eq!(0, compare::distinct_char_count(&""));
+        assert_eq!(4, compare::distinct_char_count(&"éaéAa日日"));
+        assert_eq!(128, compare::distinct_char_count(&(0u8..128).map(|b| b as char).collect::<String>()));
+    }
 
-        let needle_len = needle.len();
-        let haystack_len = haystack.len();
-        let loops = (haystack_len + 63) / 64; // Calculate the number of 64-byte chunks
-        let first_byte = needle[0]; // First byte of the needle for initial comparison
+    #[test]
+    fn test_starts_ends_with_any() {
+        assert_eq!(Some(1), compare::starts_with_any(&"https://x", &["http://", "https://"]));
+        assert_eq!(Some(0), compare::starts_with_any(&"http://x", &["http://", "https://"]));
+        assert_eq!(None, compare::starts_with_any(&"ftp://x", &["http://", "https://"]));
+        assert_eq!(Some(0), compare::starts_with_any(&"abc", &["a", "ab"]));
+        assert_eq!(Some(1), compare::ends_with_any(&"main.rs", &[".toml", ".rs"]));
+        assert_eq!(None, compare::ends_with_any(&"main.rs", &[".toml"]));
+        assert_eq!(None, compare::ends_with_any(&"main.rs", &Vec::<String>::new()));
+    }
 
-        for loop_index in 0..loops {
-            let offset = loop_index * 64;
+    #[test]
+    fn test_char_positions_bitmap() {
+        let mut base = "a quick brown fox jumps over the lazy dog, é".repeat(5);
+        base.push('a');
+        for c in ['a', 'o', ' ', 'é', 'z', 'Z'] {
+            let bitmap = compare::char_positions_bitmap(&base, c);
+            let expected: Vec<usize> = base.char_indices().filter(|&(_, found)| found == c).map(|(pos, _)| pos).collect();
+            assert_eq!(base.len().div_ceil(64), bitmap.len());
+            assert_eq!(expected.len(), compare::bitmap_count(&bitmap));
+            assert_eq!(expected, compare::bitmap_iter(&bitmap).collect::<Vec<usize>>());
+        }
 
-            // Fetch 64 bytes from the haystack, or fewer if near the end
-            let chunk = simd_u8x64_from_offset(haystack, offset);
-            let compare_mask: Simd<u8, 64> = chunk.simd_eq(u8x64::splat(first_byte)).to_int().cast();
+        let a = compare::char_positions_bitmap(&"abab", 'a');
+        let b = compare::char_positions_bitmap(&"abab", 'b');
+        let either: Vec<u64> = a.iter().zip(&b).map(|(a, b)| a | b).collect();
+        assert_eq!(4, compare::bitmap_count(&either));
+        assert!(compare::char_positions_bitmap(&"", 'a').is_empty());
+    }
 
-            // If no matches for the first byte, skip to the next chunk
-            if compare_mask.reduce_max() == 0 {
-                continue;
-            }
+    #[test]
+    fn test_match_density() {
+        assert_eq!(0.5, compare::match_density(&"abab----", &"ab"));
+        assert_eq!(1.0, compare::match_density(&"aaaa", &"aa"));
+        assert_eq!(2.0 / 3.0, compare::match_density(&"aaa", &"aa"));
+        assert_eq!(0.0, compare::match_density(&"xyz", &"ab"));
+        assert_eq!(0.0, compare::match_density(&"", &"ab"));
+    }
 
-            // Check subsequent bytes of the needle
-            for i in 0..64.min(haystack_len - offset) {
-                if i + needle_len > haystack_len {
-                    break; // Not enough remaining bytes in the haystack
-                }
+    #[test]
+    fn test_longest_palindrome() {
+        let range = compare::longest_palindrome(&"babad").unwrap();
+        assert!(["bab", "aba"].contains(&&"babad"[range.0..range.1]));
+        assert_eq!(Some((1, 3)), compare::longest_palindrome(&"cbbd"));
+        assert_eq!(Some((0, 1)), compare::longest_palindrome(&"abc"));
+        assert_eq!(Some((0, 7)), compare::longest_palindrome(&"racecar"));
+        assert_eq!(None, compare::longest_palindrome(&""));
+        let base = "xéaébz";
+        let (start, end) = compare::longest_palindrome(&base).unwrap();
+        assert_eq!("éaé", &base[start..end]);
 
-                if haystack[offset + i..offset + i + needle_len] == needle[..] {
-                    return true;
-                }
-            }
+        for base in ["forgeeksskeegfor", "abacdfgdcaba", "aaaabaaa", "abcbaXabccba"] {
+            let (start, end) = compare::longest_palindrome(&base).unwrap();
+            let longest = (0..base.len())
+                .flat_map(|s| (s + 1..=base.len()).map(move |e| (s, e)))
+                .filter(|&(s, e)| compare::is_palindrome(&&base[s..e]))
+                .map(|(s, e)| e - s)
+                .max()
+                .unwrap();
+            assert_eq!(longest, end - start);
+            assert!(compare::is_palindrome(&&base[start..end]));
         }
+    }
 
-        false
+    #[test]
+    fn test_char_frequencies_sorted() {
+        assert_eq!(vec![('a', 3), ('b', 2), ('c', 1)], compare::char_frequencies_sorted(&"aaabbc"));
+        assert_eq!(vec![('b', 2), ('é', 2), ('a', 1), ('z', 1)], compare::char_frequencies_sorted(&"zébaéb"));
+        assert!(compare::char_frequencies_sorted(&"").is_empty());
     }
 
+    #[test]
+    fn test_bytes_search() {
+        let haystack: [u8; 8] = [0x00, 0xFF, 0xFE, 0x89, 0xFF, 0xFE, 0xFF, 0x01];
+        // 0xFF and 0xFE never occur in UTF-8
+        let needle: [u8; 2] = [0xFF, 0xFE];
+        assert!(compare::contains_bytes(&haystack, &needle));
+        assert_eq!(Some(1), compare::find_first_bytes(&haystack, &needle));
+        assert_eq!(vec![(1, 3), (4, 6)], compare::find_all_bytes(&haystack, &needle));
+        assert!(!compare::contains_bytes(&haystack, &[0xFE, 0x01]));
+        assert!(!compare::contains_bytes(&haystack, &[]));
+        assert_eq!(None, compare::find_first_bytes(&needle, &haystack));
 
-    pub fn substring_count_simd<B, S>(haystack: &B, needle: &S) -> usize
-        where B: ToString, S: ToString
-    {
-        let binding = haystack.to_string();
-        let haystack = binding.as_bytes();
-        let binding = needle.to_string();
-        let needle = binding.as_bytes();
+        let mut long = vec![0xAAu8; 200];
+        long.extend_from_slice(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        assert_eq!(Some(200), compare::find_first_bytes(&long, &[0xDE, 0xAD, 0xBE, 0xEF]));
+        assert_eq!(197, compare::find_all_bytes(&long, &[0xAA, 0xAA, 0xAA, 0xAA]).len());
+    }
 
-        assert!(haystack.len() >= needle.len() && needle.len() > 0);
+    #[test]
+    fn test_jaro_winkler() {
+        assert!((compare::jaro_winkler(&"martha", &"marhta") - 0.961).abs() < 0.001);
+        assert!((compare::jaro_winkler(&"dixon", &"dicksonx") - 0.813).abs() < 0.001);
+        assert!((compare::jaro_winkler(&"dwayne", &"duane") - 0.84).abs() < 0.001);
+        assert_eq!(1.0, compare::jaro_winkler(&"same", &"same"));
+        assert_eq!(0.0, compare::jaro_winkler(&"abc", &"xyz"));
+        assert_eq!(0.0, compare::jaro_winkler(&"abc", &""));
+        assert_eq!(1.0, compare::jaro_winkler(&"", &""));
+    }
 
-        let haystack_len = haystack.len();
-        let needle_len = needle.len();
-        let loops = (haystack_len + 63) / 64;
-        let first_byte = needle[0];
-        let mut count = 0i32;
-
-        'outer: for chunk_index in 0..loops {
-            let offset = chunk_index * 64;
-            let mut chunk_total = u8x64::splat(0);
-            let res = simd_u8x64_from_offset(haystack, offset)
-                .simd_eq(u8x64::splat(first_byte))
-                .to_int()
-                .cast();
-
-            chunk_total = chunk_total.sub(res);
-            if chunk_total.reduce_max() == 0 {
-                continue;
-            }
+    #[test]
+    fn test_searcher() {
+        let searcher = compare::Searcher::new(&"test");
+        let base = "This is my test string! test test!";
+        assert!(searcher.contains(&base));
+        assert!(!searcher.contains(&"tes"));
+        assert_eq!(compare::find_all_exact(&base, &"test"), searcher.find_all(&base));
+        assert_eq!(3, searcher.count(&base));
 
-            let mut current_max = 1usize;
-            let mut last_max = 0usize;
-            let mut sub_offset = offset + 1;
-            let mut needle_pos = 1;
-            while last_max < current_max && needle_pos < needle_len {
-                last_max = current_max;
-                let res = simd_u8x64_from_offset(haystack, sub_offset)
-                    .simd_eq(u8x64::splat(needle[needle_pos])).to_int().cast();
-                chunk_total -= res;
-                current_max = chunk_total.reduce_max() as usize;
-                if current_max > last_max && needle_pos == needle_len - 1 {
-                    let mask = chunk_total.simd_eq(u8x64::splat(current_max as u8));
-                    count -= mask.to_int().reduce_sum() as i32;
-                    continue 'outer;
-                }
-                sub_offset += 1;
-                needle_pos += 1;
-            }
+        let overlapping = compare::Searcher::new(&"aa");
+        assert_eq!(vec![(0, 2), (1, 3), (2, 4)], overlapping.find_all(&"aaaa"));
+        assert_eq!(2, overlapping.count(&"aaaa"));
+        assert!(!compare::Searcher::new(&"").contains(&"abc"));
+
+        let mut long = "ab".repeat(100);
+        long.push_str("needle");
+        let needle = compare::Searcher::new(&"needle");
+        assert_eq!(vec![(200, 206)], needle.find_all(&long));
+        let single = compare::Searcher::new(&"b");
+        assert_eq!(100, single.count(&long));
+        for line in ["x", "abab", "needle", "ne edle"] {
+            assert_eq!(line.contains("needle"), needle.contains(&line));
         }
-        count as usize
     }
-}
 
+    #[test]
+    fn test_find_all_exact_capped() {
+        let base = "ab ab ab ab ab";
+        assert_eq!(5, compare::find_all_exact(&base, &"ab").len());
+        assert_eq!(vec![(0, 2), (3, 5)], compare::find_all_exact_capped(&base, &"ab", 2));
+        assert_eq!(5, compare::find_all_exact_capped(&base, &"ab", 10).len());
+        assert!(compare::find_all_exact_capped(&base, &"ab", 0).is_empty());
+    }
 
-#[cfg(test)]
-mod tests {
-    use std::collections::HashMap;
-    use std::fmt::Display;
-    use super::*;
+    #[test]
+    fn test_matches_glob() {
+        assert!(compare::matches_glob(&"file.txt", &"*.txt"));
+        assert!(compare::matches_glob(&"file.txt", &"f?le.txt"));
+        assert!(!compare::matches_glob(&"file.txt.bak", &"*.txt"));
+        assert!(!compare::matches_glob(&"fle.txt", &"f?le.txt"));
+        assert!(compare::matches_glob(&"", &"*"));
+        assert!(!compare::matches_glob(&"", &"?"));
+        assert!(compare::matches_glob(&"abcbcd", &"a*bcd"));
+        assert!(compare::matches_glob(&"mississippi", &"m*iss*p?i"));
+        assert!(compare::matches_glob(&"héllo", &"h?llo"));
+        assert!(compare::matches_glob(&"what?", &"what\\?"));
+        assert!(!compare::matches_glob(&"whatx", &"what\\?"));
+        assert!(compare::matches_glob(&"a*b", &"a\\*b"));
+        assert!(!compare::matches_glob(&"axb", &"a\\*b"));
+        assert!(compare::matches_glob(&"a\\b", &"a\\\\b"));
+        assert!(compare::matches_glob(&"src/lib.rs", &"src/**"));
+    }
 
     #[test]
-    fn test_substring_char_group_count() {
-        let str1 = String::from("aabbccba");
-        let char_group = vec!['c', 'a', 'b'];
-        let result = compare::substring_char_group_count(&str1, &char_group);
-        let mut expected: HashMap<String, usize> = HashMap::new();
-        expected.insert("cba".to_string(), 1);
-        expected.insert("abbc".to_string(), 1);
-        expected.insert("abbccba".to_string(), 1);
-        expected.insert("bbccba".to_string(), 1);
-        expected.insert("ccba".to_string(), 1);
-        expected.insert("aabbccb".to_string(), 1);
-        expected.insert("aabbc".to_string(), 1);
-        expected.insert("abbccb".to_string(), 1);
-        expected.insert("abbcc".to_string(), 1);
-        expected.insert("bccba".to_string(), 1);
-        expected.insert("aabbcc".to_string(), 1);
-        expected.insert("aabbccba".to_string(), 1);
-        assert_eq!(result, expected);
+    #[cfg(feature = "std")]
+    fn test_shannon_entropy() {
+        assert_eq!(0.0, compare::shannon_entropy(&""));
+        assert_eq!(0.0, compare::shannon_entropy(&"aaaaaaaa"));
+        assert_eq!(1.0, compare::shannon_entropy(&"abab"));
+        assert_eq!(2.0, compare::shannon_entropy(&"abcd"));
+
+        let ascii: Vec<u8> = (0..128).collect();
+        let noisy = pseudo_random_ascii(1, 1 << 16, &ascii);
+        assert!(compare::shannon_entropy_bytes(noisy.as_bytes()) > 6.99);
+        assert!(compare::shannon_entropy_bytes(noisy.as_bytes()) <= 7.0);
+        assert_eq!(0.0, compare::shannon_entropy_bytes(&[0x42; 100]));
+        assert_eq!(0.0, compare::shannon_entropy_bytes(&[]));
     }
 
     #[test]
-    fn test_char_count() {
-        let str1 = String::from("abbccc748237489237498237482374982374892734987423982734982347984732984ccc");
-        let chars = vec!['a', 'b', 'c'];
-        let result = compare::count_chars(&str1, &chars);
-        let mut expected: HashMap<char, usize> = HashMap::new();
-        expected.insert('c', 6);
-        expected.insert('a', 1);
-        expected.insert('b', 2);
-        assert_eq!(result, expected);
+    fn test_shared_ngrams() {
+        assert_eq!(vec!["cd", "de"], compare::shared_ngrams(&"abcde", &"zcdef", 2));
+        assert_eq!(vec!["ab"], compare::shared_ngrams(&"ababab", &"xxabxx", 2));
+        assert!(compare::shared_ngrams(&"abc", &"xyz", 1).is_empty());
+        assert!(compare::shared_ngrams(&"ab", &"ab", 3).is_empty());
+        assert_eq!(vec!["é"], compare::shared_ngrams(&"éa", &"bé", 1));
     }
 
     #[test]
-    fn test_char_count_simd() {
-        let str1 = String::from("abbccc748237489237498237482374982374892734987423982734982347984732984ccc");
-        let chars = vec!['a', 'b', 'c'];
-        let result = compare::count_chars_simd(&str1, &chars);
-        let mut expected: HashMap<char, usize> = HashMap::new();
-        expected.insert('c', 6);
-        expected.insert('a', 1);
-        expected.insert('b', 2);
-        assert_eq!(result, expected);
+    fn test_find_all_matches() {
+        assert_eq!(vec!["test", "test"], compare::find_all_matches(&"test x test", &"test"));
+        assert!(compare::find_all_matches(&"Test x TEST", &"test").is_empty());
+        assert_eq!(vec!["Test", "TEST"], compare::find_all_matches_ignore_case(&"Test x TEST", &"test"));
+        assert_eq!(vec!["ÉTÉ", "été"], compare::find_all_matches_ignore_case(&"ÉTÉ été", &"Été"));
+        assert_eq!(vec!["Aa", "aA"], compare::find_all_matches_ignore_case(&"AaA", &"aa"));
+        assert!(compare::find_all_matches_ignore_case(&"a", &"ab").is_empty());
+        assert!(compare::find_all_matches_ignore_case(&"abc", &"").is_empty());
     }
 
     #[test]
-    fn test_append() {
-        let mut str1 = String::from("123");
-        let str2 = String::from("test");
-        modify::append(&mut str1, &str2);
-        assert_eq!(String::from("123test"), str1);
+    fn test_split_iter() {
+        let mut fields = compare::split_iter("a,b", ",");
+        assert_eq!(Some("a"), fields.next());
+        assert_eq!(Some("b"), fields.next());
+        assert_eq!(None, fields.next());
+        assert_eq!(None, fields.next());
+
+        assert_eq!(vec!["a", "b", ""], compare::split_iter("a,b,", ",").collect::<Vec<&str>>());
+        assert_eq!(vec![""], compare::split_iter("", ",").collect::<Vec<&str>>());
+        for (base, delim) in [("a::b::::c", "::"), (",x,", ","), ("no delim", ";"), ("é,é", "é")] {
+            assert_eq!(compare::split(&base, &delim), compare::split_iter(base, delim).collect::<Vec<&str>>());
+        }
     }
 
     #[test]
-    fn test_replace() {
-        let mut str1 = String::from("123123123test123123123test12teest");
-        let str2 = String::from("test");
-        let str3 = String::from("replaced");
-        modify::replace(&mut str1, &str2, &str3);
-        assert_eq!("123123123replaced123123123replaced12teest", str1);
+    #[cfg(feature = "std")]
+    fn test_count_chars_in_range() {
+        let result = compare::count_chars_in_range(&"xaxaxa", (2, 6), &['a']);
+        assert_eq!(result.get(&'a'), Some(&2));
+        let result = compare::count_chars_in_range(&"xaxaxa", (0, 0), &['a']);
+        assert_eq!(result.get(&'a'), Some(&0));
     }
 
     #[test]
-    fn test_contains() {
-        let str1 = String::from("123123123test123123123");
-        let str2 = String::from("test");
-        let result = compare::contains(&str1, &str2);
-        assert_eq!(true, result);
+    #[cfg(feature = "std")]
+    #[should_panic]
+    fn test_count_chars_in_range_mid_char() {
+        compare::count_chars_in_range(&"héllo", (2, 4), &['l']);
     }
 
     #[test]
-    fn test_contains_simd() {
-        let str1 = String::from("123123123test123123123");
-        let str2 = String::from("test");
-        let result = compare::contains_simd2(&str1, &str2);
-        assert_eq!(true, result);
+    fn test_levenshtein_weighted() {
+        assert_eq!(3, compare::levenshtein_weighted(&"kitten", &"sitting", 1, 1, 1));
+        assert_eq!(1, compare::levenshtein_weighted(&"cat", &"cut", 1, 1, 1));
+        assert_eq!(2, compare::levenshtein_weighted(&"cat", &"cut", 1, 1, 10));
+        assert_eq!(6, compare::levenshtein_weighted(&"", &"abc", 2, 1, 1));
+        assert_eq!(0, compare::levenshtein_weighted(&"same", &"same", 5, 5, 5));
     }
 
     #[test]
-    fn test_contains_simd2() {
-        let str1 = String::from("12312312312312312312312312312312312312t1e3s2t123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123123test");
-        let str2 = String::from("test");
-        let result = compare::contains_simd2(&str1, &str2);
-        assert_eq!(true, result);
+    fn test_popcount() {
+        assert_eq!(0, compare::popcount(&""));
+        assert_eq!(7, compare::popcount(&"ac"));
+        let str1 = "\x7f".repeat(100);
+        assert_eq!(700, compare::popcount(&str1));
+        let str1 = "\u{ff}".repeat(40);
+        assert_eq!(40 * 11, compare::popcount(&str1));
     }
 
     #[test]
-    fn test_contains_simd3() {
-        let str1 = String::from("12312312312312312312312312312312312312t1e3123123123123123111111test12312312312312312312312312312312312312t1e312312312312312311111112312312312312312312312312312312312312t1e3123123123123123111111");
-        let str2 = String::from("test");
-        let result = compare::contains_simd2(&str1, &str2);
-        assert_eq!(true, result);
+    fn test_line_at() {
+        let str1 = "one\r\ntwo\n\nthree\n";
+        assert_eq!(Some((0, 3)), compare::line_at(&str1, 0));
+        assert_eq!(Some((5, 8)), compare::line_at(&str1, 1));
+        assert_eq!(Some((9, 9)), compare::line_at(&str1, 2));
+        assert_eq!(Some((10, 15)), compare::line_at(&str1, 3));
+        assert_eq!(None, compare::line_at(&str1, 4));
+        assert_eq!(None, compare::line_at(&"", 0));
     }
 
     #[test]
-    fn test_contains_simd4() {
-        let str1 = String::from("12312312312312312312312312312312312312t1e3123123123123123111111test123123123123123123test2312312312312312t1e312312312312312311111112312312312312312312312312312312312312t1e312312312312312311111112312312312312312312312312312312312312t1e3123123123123123111111test123123123123123123test2312312312312312t1e312312312312312311111112312312312312312312312312312312312312t1e312312312312312311111112312312312312312312312312312312312312t1e3123123123123123111111test123123123123123123test2312312312312312t1e312312312312312311111112312312312312312312312312312312312312t1e3123123123123123111111");
-        let str2 = String::from("test");
-        let result = compare::sub_count_simd2(&str1, &str2);
-        assert_eq!(6, result);
+    fn test_byte_to_line_col() {
+        let str1 = "one\r\ntwo\nthree";
+        assert_eq!(Some((0, 0)), compare::byte_to_line_col(&str1, 0));
+        assert_eq!(Some((0, 3)), compare::byte_to_line_col(&str1, 3));
+        assert_eq!(Some((1, 1)), compare::byte_to_line_col(&str1, 6));
+        assert_eq!(Some((2, 5)), compare::byte_to_line_col(&str1, 14));
+        assert_eq!(None, compare::byte_to_line_col(&str1, 15));
     }
 
     #[test]
-    fn test_find_all_exact() {
-        let str1 = String::from("123test113test444testtest");
-        let str2 = String::from("test");
-        let result = compare::find_all_exact(&str1, &str2);
-        let expected: Vec<(usize, usize)> = vec![(3, 7), (10, 14), (17, 21), (21, 25)];
-        assert_eq!(expected, result);
+    fn test_contains_all() {
+        assert!(compare::contains_all(&"cat and dog", &["cat", "dog"]));
+        assert!(!compare::contains_all(&"only cat", &["cat", "dog"]));
+        assert!(compare::contains_all(&"anything", &["", "thing"]));
+        assert!(compare::contains_all(&"anything", &[] as &[&str]));
+    }
+
+    #[test]
+    fn test_substring_count_kmp() {
+        assert_eq!(2, compare::substring_count_kmp(&"aaaa", &"aa"));
+        assert_eq!(0, compare::substring_count_kmp(&"ab", &""));
+
+        let pathological = "a".repeat(1 << 20);
+        assert_eq!(0, compare::substring_count_kmp(&pathological, &"aaab"));
+    }
+
+    #[test]
+    fn test_find_with_context() {
+        let sentence = "the quick brown fox jumps";
+        assert_eq!(Some(String::from("ick brown fo")), compare::find_with_context(&sentence, &"brown", 4, 3));
+        assert_eq!(Some(String::from("brown")), compare::find_with_context(&sentence, &"brown", 0, 0));
+        assert_eq!(Some(String::from(sentence)), compare::find_with_context(&sentence, &"brown", 100, 100));
+        assert_eq!(Some(String::from("é-ü-é")), compare::find_with_context(&"aé-ü-éb", &"-ü-", 1, 1));
+        assert_eq!(None, compare::find_with_context(&sentence, &"cat", 4, 4));
     }
 
     struct ToStringStruct {
@@ -741,4 +6385,269 @@ mod tests {
 
         assert_eq!(string_builder.build(), "1234ctest55{ \"a_string\": \"struct_string\", \"a_number\": \"4321\" }".to_string());
     }
+
+    #[test]
+    fn test_stringbuilder_as_bytes() {
+        let mut string_builder = builder::StringBuilder::new();
+        string_builder.append("test").append(123);
+        assert_eq!(string_builder.build().as_bytes(), string_builder.as_bytes());
+    }
+
+    #[test]
+    fn test_stringbuilder_separated() {
+        let mut string_builder = builder::StringBuilder::separated(", ");
+        assert_eq!("", string_builder.build());
+        string_builder.append("a").append("b").append("c");
+        assert_eq!("a, b, c", string_builder.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_append_map() {
+        let mut string_builder = builder::StringBuilder::new();
+        string_builder.append_map(0..3, |n| n * 2);
+        assert_eq!("024", string_builder.build());
+
+        let mut separated_builder = builder::StringBuilder::separated(",");
+        separated_builder.append("x").append_map(vec!["a", "b"], |s| s.to_uppercase());
+        assert_eq!("x,A,B", separated_builder.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_append_padded() {
+        let mut string_builder = builder::StringBuilder::new();
+        string_builder
+            .append_padded("7", 4, '0', false)
+            .append("|")
+            .append_padded("ab", 4, '.', true)
+            .append("|")
+            .append_padded("toolong", 4, ' ', false);
+        assert_eq!("0007|ab..|toolong", string_builder.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_append_utf16() {
+        let mut string_builder = builder::StringBuilder::new();
+        let units: Vec<u16> = "hi".encode_utf16().collect();
+        string_builder.append_utf16(&units).append_utf16(&[0xD800, 0x0021]);
+        assert_eq!("hi\u{FFFD}!", string_builder.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_insert() {
+        let mut string_builder = builder::StringBuilder::new();
+        string_builder.append("abc").insert(1, "XX");
+        assert_eq!("aXXbc", string_builder.build());
+
+        let mut multibyte_builder = builder::StringBuilder::new();
+        multibyte_builder.append("éé").insert(1, 'x').insert(3, 'y');
+        assert_eq!("éxéy", multibyte_builder.build());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_stringbuilder_insert_out_of_bounds() {
+        let mut string_builder = builder::StringBuilder::new();
+        string_builder.append("abc").insert(4, "XX");
+    }
+
+    #[test]
+    fn test_stringbuilder_append_escaped() {
+        let mut json_builder = builder::StringBuilder::new();
+        json_builder.append_json_escaped("a\"b").append("|").append_json_escaped("c\\d\n\u{01}");
+        assert_eq!("a\\\"b|c\\\\d\\n\\u0001", json_builder.build());
+
+        let mut html_builder = builder::StringBuilder::new();
+        html_builder.append_html_escaped("<a href=\"x\">Tom & Jerry's</a>");
+        assert_eq!("&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;", html_builder.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_operators() {
+        let mut string_builder = builder::StringBuilder::new();
+        string_builder += "a";
+        string_builder += "b";
+        assert_eq!("ab", string_builder.build());
+
+        string_builder += String::from("c");
+        let string_builder = string_builder + "d" + "e";
+        assert_eq!("abcde", string_builder.build());
+
+        let mut separated_builder = builder::StringBuilder::separated(",");
+        separated_builder += "x";
+        separated_builder += "y";
+        assert_eq!("x,y", separated_builder.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_append_char() {
+        let mut string_builder = builder::StringBuilder::new();
+        string_builder.append_char('é');
+        assert_eq!(&[0xC3, 0xA9], string_builder.as_bytes());
+
+        let mut long_builder = builder::StringBuilder::new();
+        for _ in 0..100_000 {
+            long_builder.append_char('a');
+        }
+        assert_eq!(100_000, long_builder.build().len());
+    }
+
+    #[test]
+    fn test_stringbuilder_append_all() {
+        let mut all = builder::StringBuilder::new();
+        all.append_all(vec!["a", "b", "c"]).append_all(1..=3);
+        assert_eq!("abc123", all.build());
+        let mut separated = builder::StringBuilder::separated(",");
+        separated.append("x").append_all(["y", "z"]);
+        assert_eq!("x,y,z", separated.build());
+        let mut empty = builder::StringBuilder::new();
+        empty.append_all(Vec::<String>::new());
+        assert_eq!("", empty.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_replace() {
+        let mut separated = builder::StringBuilder::separated("_");
+        separated.append("a").append("b").append("c").replace("_", "-");
+        assert_eq!("a-b-c", separated.build());
+        separated.append("d");
+        assert_eq!("a-b-c_d", separated.build());
+        let mut short = builder::StringBuilder::new();
+        short.append("ab").replace("abc", "x").replace("", "x");
+        assert_eq!("ab", short.build());
+        short.replace("ab", "xyz").append_char('!');
+        assert_eq!("xyz!", short.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_append_grouped() {
+        let mut grouped = builder::StringBuilder::separated(" ");
+        grouped.append_grouped(1234567, ',')
+            .append_grouped(-1234567, ',')
+            .append_grouped(999, ',')
+            .append_grouped(-12, ',')
+            .append_grouped(0, ',')
+            .append_grouped(100000, '_')
+            .append_grouped(i64::MIN, ',');
+        assert_eq!("1,234,567 -1,234,567 999 -12 0 100_000 -9,223,372,036,854,775,808", grouped.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_append_float() {
+        let mut floats = builder::StringBuilder::separated(",");
+        floats.append_float(std::f64::consts::PI, 2)
+            .append_float(2.5, 0)
+            .append_float(-0.125, 3)
+            .append_float(1.0, 4)
+            .append_float(f64::NAN, 2)
+            .append_float(f64::INFINITY, 2)
+            .append_float(f64::NEG_INFINITY, 2);
+        assert_eq!("3.14,2,-0.125,1.0000,NaN,inf,-inf", floats.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_append_lines() {
+        let mut lines = builder::StringBuilder::new();
+        lines.append_lines(["a", "b"]);
+        assert_eq!("a\nb\n", lines.build());
+        lines.append_lines(vec![1, 2]).append_lines(Vec::<String>::new());
+        assert_eq!("a\nb\n1\n2\n", lines.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_append_int_right_aligned() {
+        let mut table = builder::StringBuilder::separated("|");
+        table.append_int_right_aligned(42, 5)
+            .append_int_right_aligned(-7, 4)
+            .append_int_right_aligned(123456, 3)
+            .append_int_right_aligned(0, 0);
+        assert_eq!("   42|  -7|123456|0", table.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_append_hex() {
+        let mut hex = builder::StringBuilder::separated(" ");
+        hex.append_hex(&[0xDE, 0xAD], true)
+            .append_hex(&[0xBE, 0xEF, 0x00, 0x0A], false)
+            .append_hex(&[], true);
+        assert_eq!("DEAD beef000a ", hex.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_append_separator() {
+        let mut sb = builder::StringBuilder::new();
+        sb.append_separator(", ");
+        assert_eq!("", sb.build());
+        for item in ["a", "b", "c"] {
+            sb.append_separator(", ").append(item);
+        }
+        assert_eq!("a, b, c", sb.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_append_query_param() {
+        let mut url = builder::StringBuilder::new();
+        url.append_query_param("a", "1")
+            .append_query_param("b", "hi there");
+        assert_eq!("?a=1&b=hi%20there", url.build());
+
+        let mut url = builder::StringBuilder::new();
+        url.append("https://example.com/search")
+            .append_query_param("q&a", "caf\u{e9}/~");
+        assert_eq!("https://example.com/search?q%26a=caf%C3%A9%2F~", url.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_checkpoint_rollback() {
+        let mut sb = builder::StringBuilder::separated(", ");
+        let empty = sb.checkpoint();
+        sb.append("a");
+        let checkpoint = sb.checkpoint();
+        sb.append("b").append("c");
+        assert_eq!("a, b, c", sb.build());
+        sb.rollback_to(checkpoint).append("d");
+        assert_eq!("a, d", sb.build());
+        sb.rollback_to(empty).append("e");
+        assert_eq!("e", sb.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_rollback_past_query_param() {
+        let mut url = builder::StringBuilder::new();
+        url.append("https://x/a");
+        let checkpoint = url.checkpoint();
+        url.append_query_param("q", "1");
+        url.rollback_to(checkpoint).append_query_param("r", "2");
+        assert_eq!("https://x/a?r=2", url.build());
+    }
+
+    #[test]
+    fn test_stringbuilder_rollback_past_first_separated_value() {
+        let mut sb = builder::StringBuilder::separated(",");
+        sb.append_query_param("k", "v");
+        let checkpoint = sb.checkpoint();
+        sb.append("a");
+        sb.rollback_to(checkpoint).append("b");
+        assert_eq!("?k=vb", sb.build());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_stringbuilder_rollback_mid_char() {
+        let mut ascii = builder::StringBuilder::new();
+        ascii.append("a");
+        let checkpoint = ascii.checkpoint();
+        let mut sb = builder::StringBuilder::new();
+        sb.append("é");
+        sb.rollback_to(checkpoint);
+    }
+
+    #[test]
+    fn test_stringbuilder_append_json_field() {
+        let mut json = builder::StringBuilder::new();
+        json.append("{")
+            .append_json_field("a", "1", true)
+            .append_json_field("b", "x\"y", false)
+            .append("}");
+        assert_eq!(r#"{"a":"1","b":"x\"y"}"#, json.build());
+    }
 }